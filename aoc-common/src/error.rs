@@ -0,0 +1,158 @@
+use std::fmt;
+
+/// What went wrong while parsing, without the where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Couldn't read a number.
+    InvalidNumber,
+    /// Found something other than what the input should have here.
+    Expected(&'static str),
+    /// A grid row that isn't as wide as the first row.
+    RowWidth { expected: usize, found: usize },
+    /// Got to the end of the input without finding something it needs.
+    Missing(&'static str),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidNumber => write!(f, "expected a number"),
+            ParseErrorKind::Expected(expected) => write!(f, "expected {}", expected),
+            ParseErrorKind::RowWidth { expected, found } => {
+                write!(f, "row is {} wide, expected {}", found, expected)
+            }
+            ParseErrorKind::Missing(missing) => write!(f, "couldn't find {}", missing),
+        }
+    }
+}
+
+/// The input couldn't be parsed.  
+/// Knows the day, the line and column (both starting from 1) and the text that broke it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Shared parsers like [`crate::Grid::parse`] don't know which day they're parsing for.
+    /// The day fills this in with [`ParseError::with_day`].
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    pub fn new(kind: ParseErrorKind, line: usize, column: usize, text: &str) -> Self {
+        Self {
+            day: None,
+            line,
+            column,
+            text: text.to_string(),
+            kind,
+        }
+    }
+
+    /// An error at the start of `rest`, which has to be a slice of `input`.  
+    /// This is how nom hands back where it stopped.
+    pub fn at(input: &str, rest: &str, kind: ParseErrorKind) -> Self {
+        // `rest` might be a slice of a single line rather than a suffix of the whole input,
+        // so work out where it starts from the pointers rather than the lengths.
+        // Parsers often stop on the space before the bad bit, so skip ahead to what is actually wrong.
+        let rest = rest.trim_start_matches([' ', '\t']);
+        let offset = rest.as_ptr() as usize - input.as_ptr() as usize;
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+
+        // The offending text is the word that starts here.
+        let text = rest.split_whitespace().next().unwrap_or_default();
+        let text = if rest.starts_with('\n') { "" } else { text };
+
+        Self::new(kind, line, column, text)
+    }
+
+    /// An error for when the whole input was read and something was still missing.
+    pub fn at_end(input: &str, kind: ParseErrorKind) -> Self {
+        Self::at(input, &input[input.len()..], kind)
+    }
+
+    /// Turn a nom error into a [`ParseError`] pointing at where nom gave up.
+    pub fn from_nom(
+        input: &str,
+        error: nom::Err<nom::error::Error<&str>>,
+        kind: ParseErrorKind,
+    ) -> Self {
+        match error {
+            nom::Err::Error(error) | nom::Err::Failure(error) => Self::at(input, error.input, kind),
+            nom::Err::Incomplete(_) => Self::at_end(input, kind),
+        }
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "Day {}, line {}", day, self.line)?;
+        } else {
+            write!(f, "Line {}", self.line)?;
+        }
+        write!(f, ", column {}: {}", self.column, self.kind)?;
+
+        if self.text.is_empty() {
+            write!(f, ", found the end of the line")
+        } else {
+            write!(f, ", found `{}`", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location_from_rest() {
+        let input = "1 2\n3 x 5";
+        let error = ParseError::at(input, &input[6..], ParseErrorKind::InvalidNumber);
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 3);
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn location_inside_a_line() {
+        let input = "1 2\n3 x 5\n6";
+        let line = input.split('\n').nth(1).unwrap();
+        let error = ParseError::at(input, &line[2..], ParseErrorKind::InvalidNumber);
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn location_at_end() {
+        let error = ParseError::at_end("ab\ncd", ParseErrorKind::Missing("the guard"));
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "");
+    }
+
+    #[test]
+    fn display() {
+        let error = ParseError::new(ParseErrorKind::InvalidNumber, 3, 5, "abc").with_day(1);
+        assert_eq!(
+            error.to_string(),
+            "Day 1, line 3, column 5: expected a number, found `abc`"
+        );
+
+        let error = ParseError::new(ParseErrorKind::Expected("a digit"), 1, 4, "");
+        assert_eq!(
+            error.to_string(),
+            "Line 1, column 4: expected a digit, found the end of the line"
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{ParseError, ParseErrorKind, Position};
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from the puzzle text, converting every character with `parse_cell`.
+    ///
+    /// Every row must be the same width.
+    pub fn parse(input: &str, mut parse_cell: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let row_width = line.chars().count();
+            if y == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(ParseError::new(
+                    ParseErrorKind::RowWidth {
+                        expected: width,
+                        found: row_width,
+                    },
+                    y + 1,
+                    row_width.min(width) + 1,
+                    line,
+                ));
+            }
+
+            cells.extend(line.chars().map(&mut parse_cell));
+            height += 1;
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Is the position inside the grid?
+    pub fn in_bounds(&self, position: Position) -> bool {
+        position.0 >= 0
+            && position.1 >= 0
+            && (position.0 as usize) < self.width
+            && (position.1 as usize) < self.height
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        if !self.in_bounds(position) {
+            return None;
+        }
+
+        Some(position.1 as usize * self.width + position.0 as usize)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Replace the cell at `position`, returning what was there.  
+    /// Returns `None` and does nothing if the position is off the grid.
+    pub fn set(&mut self, position: Position, value: T) -> Option<T> {
+        self.get_mut(position)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Every position on the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len())
+            .map(move |index| Position((index % width) as i32, (index / width) as i32))
+    }
+
+    /// Every cell alongside its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Build a new grid of the same size by converting every cell.
+    pub fn map<U>(&self, convert: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(convert).collect(),
+        }
+    }
+
+    /// The rows of the grid, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero chunk size, which an empty grid would give us.
+        self.cells.chunks(self.width.max(1))
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// All positions holding `value`.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid of the given size with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// The grid turned a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..self.width {
+            for x in 0..self.height {
+                cells.push(self[Position(y as i32, (self.height - 1 - x) as i32)].clone());
+            }
+        }
+
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for row in self.rows() {
+            cells.extend(row.iter().rev().cloned());
+        }
+
+        Self {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl TryFrom<&str> for Grid<char> {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, |c| c)
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "#..
+.^.
+..#";
+
+    #[test]
+    fn parse_dimensions() {
+        let grid = Grid::try_from(SAMPLE).unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.get(Position(1, 1)), Some(&'^'));
+        assert_eq!(grid[Position(2, 2)], '#');
+    }
+
+    #[test]
+    fn bounds() {
+        let grid = Grid::try_from(SAMPLE).unwrap();
+        assert!(grid.in_bounds(Position(0, 0)));
+        assert!(grid.in_bounds(Position(2, 2)));
+        assert!(!grid.in_bounds(Position(3, 0)));
+        assert!(!grid.in_bounds(Position(0, -1)));
+        assert_eq!(grid.get(Position(-1, 0)), None);
+    }
+
+    #[test]
+    fn positions_of() {
+        let grid = Grid::try_from(SAMPLE).unwrap();
+        let obstacles: Vec<Position> = grid.positions_of(&'#').collect();
+        assert_eq!(obstacles, vec![Position(0, 0), Position(2, 2)]);
+    }
+
+    #[test]
+    fn set_and_display() {
+        let mut grid = Grid::try_from(SAMPLE).unwrap();
+        assert_eq!(grid.set(Position(1, 0), 'O'), Some('.'));
+        assert_eq!(grid.set(Position(5, 5), 'O'), None);
+        assert_eq!(grid.to_string(), "#O.\n.^.\n..#");
+    }
+
+    #[test]
+    fn rotate_and_flip() {
+        let grid = Grid::try_from("ab\ncd\nef").unwrap();
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb");
+        assert_eq!(grid.flip_horizontal().to_string(), "ba\ndc\nfe");
+
+        let turned_back = (0..4).fold(grid.clone(), |grid, _| grid.rotate_clockwise());
+        assert_eq!(turned_back, grid);
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let error = Grid::try_from("...\n..").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::RowWidth {
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
@@ -0,0 +1,317 @@
+use std::{
+    borrow::Cow,
+    env, fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+    process,
+};
+
+/// Clean up line endings so parsers only ever see `\n`.  
+/// Windows checkouts give us `\r\n` and downloaded inputs end with a newline, neither of which the parsers care about.
+pub fn normalise(input: &str) -> Cow<'_, str> {
+    let input = input.trim_end_matches(['\n', '\r']);
+
+    if input.contains("\r\n") {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Where to read the puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The `input.txt` compiled into the day.
+    Bundled,
+    /// Read everything from standard input, asked for with `-`.
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// `None` falls back to the bundled input and `-` means stdin. Anything else is a path.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => InputSource::Bundled,
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self, bundled: &str) -> Result<String, InputError> {
+        match self {
+            InputSource::Bundled => Ok(bundled.to_string()),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| InputError {
+                        source: self.clone(),
+                        error,
+                    })?;
+                Ok(input)
+            }
+            InputSource::File(path) => fs::read_to_string(path).map_err(|error| InputError {
+                source: self.clone(),
+                error,
+            }),
+        }
+    }
+}
+
+/// The input couldn't be read, along with where we were trying to read it from.
+#[derive(Debug)]
+pub struct InputError {
+    pub source: InputSource,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            InputSource::Bundled => write!(f, "Couldn't read the bundled input: {}", self.error),
+            InputSource::Stdin => write!(f, "Couldn't read the input from stdin: {}", self.error),
+            InputSource::File(path) => write!(
+                f,
+                "Couldn't read the input file `{}`: {}",
+                path.display(),
+                self.error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// What a day's `main` was given on the command line.
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    input: Option<String>,
+    /// Which of the day's own flags were given.
+    flags: Vec<&'static str>,
+    /// The day's own options that were given, with their values.
+    options: Vec<(&'static str, String)>,
+}
+
+/// Find `--input <path>` (or `--input=<path>`) and any of the day's own `flags` in the command line arguments.  
+/// The day's `options` take a value the same way `--input` does.
+fn parse_args(
+    args: impl IntoIterator<Item = String>,
+    flags: &[&'static str],
+    options: &[&'static str],
+) -> Result<Args, String> {
+    let mut args = args.into_iter();
+    let mut parsed = Args {
+        input: None,
+        flags: vec![],
+        options: vec![],
+    };
+
+    while let Some(arg) = args.next() {
+        if arg == "--input" {
+            parsed.input = Some(
+                args.next()
+                    .ok_or_else(|| String::from("`--input` needs a path, or `-` for stdin"))?,
+            );
+        } else if let Some(path) = arg.strip_prefix("--input=") {
+            parsed.input = Some(path.to_string());
+        } else if let Some(flag) = flags.iter().find(|flag| **flag == arg) {
+            parsed.flags.push(flag);
+        } else if let Some(option) = options.iter().find(|option| **option == arg) {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("`{}` needs a value", option))?;
+            parsed.options.push((option, value));
+        } else if let Some((option, value)) = options.iter().find_map(|option| {
+            arg.strip_prefix(option)
+                .and_then(|rest| rest.strip_prefix('='))
+                .map(|value| (option, value))
+        }) {
+            parsed.options.push((option, value.to_string()));
+        } else {
+            let expected: Vec<String> = std::iter::once(String::from("--input <path>"))
+                .chain(flags.iter().map(|flag| flag.to_string()))
+                .chain(options.iter().map(|option| format!("{} <value>", option)))
+                .map(|expected| format!("`{}`", expected))
+                .collect();
+            return Err(format!(
+                "Unknown argument `{}`, expected {}",
+                arg,
+                expected.join(" or ")
+            ));
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Everything a day's `main` was given, with the input already read.
+#[derive(Debug)]
+pub struct DayArgs {
+    pub input: String,
+    pub flags: Vec<&'static str>,
+    pub options: Vec<(&'static str, String)>,
+}
+
+impl DayArgs {
+    pub fn flag(&self, flag: &str) -> bool {
+        self.flags.contains(&flag)
+    }
+
+    /// The value given for `option`, the last one if it was given more than once.
+    pub fn option(&self, option: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(name, _)| *name == option)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Read the input for a day's `main`, using `--input` from the command line when it is given,
+/// along with any of the day's own `flags` and `options` that take a value, like `--delay 50`.  
+/// Prints the problem and exits if the arguments aren't right or the input can't be read.
+pub fn from_args(bundled: &str, flags: &[&'static str], options: &[&'static str]) -> DayArgs {
+    let result = parse_args(env::args().skip(1), flags, options).and_then(|args| {
+        InputSource::from_arg(args.input.as_deref())
+            .read(bundled)
+            .map(|input| DayArgs {
+                input,
+                flags: args.flags,
+                options: args.options,
+            })
+            .map_err(|error| error.to_string())
+    });
+
+    or_exit(result)
+}
+
+/// Unwrap `result` in a day's `main`, or print the error the way `aoc run` would and exit.
+pub fn or_exit<T>(result: Result<T, impl fmt::Display>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn input_arg(args: Vec<String>) -> Result<Option<String>, String> {
+        parse_args(args, &[], &[]).map(|args| args.input)
+    }
+
+    #[test]
+    fn normalise_line_endings() {
+        assert_eq!(normalise("1 2\n3 4"), "1 2\n3 4");
+        assert_eq!(normalise("1 2\n3 4\n"), "1 2\n3 4");
+        assert_eq!(normalise("1 2\r\n3 4\r\n\r\n"), "1 2\n3 4");
+        assert!(matches!(normalise("1 2\n3 4\n"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn source_from_arg() {
+        assert_eq!(InputSource::from_arg(None), InputSource::Bundled);
+        assert_eq!(InputSource::from_arg(Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("other.txt")),
+            InputSource::File(PathBuf::from("other.txt"))
+        );
+    }
+
+    #[test]
+    fn input_args() {
+        assert_eq!(input_arg(args(&[])), Ok(None));
+        assert_eq!(
+            input_arg(args(&["--input", "a.txt"])),
+            Ok(Some(String::from("a.txt")))
+        );
+        assert_eq!(input_arg(args(&["--input=-"])), Ok(Some(String::from("-"))));
+        assert!(input_arg(args(&["--input"])).is_err());
+        assert!(input_arg(args(&["--nope"])).is_err());
+    }
+
+    #[test]
+    fn day_flags() {
+        let parsed = parse_args(
+            args(&["--verbose", "--input", "a.txt"]),
+            &["--verbose"],
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            parsed,
+            Args {
+                input: Some(String::from("a.txt")),
+                flags: vec!["--verbose"],
+                options: vec![],
+            }
+        );
+        assert_eq!(
+            parse_args(args(&["--nope"]), &["--verbose"], &[]),
+            Err(String::from(
+                "Unknown argument `--nope`, expected `--input <path>` or `--verbose`"
+            ))
+        );
+    }
+
+    #[test]
+    fn day_options() {
+        let parsed = parse_args(
+            args(&["--delay", "50", "--frames=0,3", "--delay=20"]),
+            &[],
+            &["--delay", "--frames"],
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.options,
+            vec![
+                ("--delay", String::from("50")),
+                ("--frames", String::from("0,3")),
+                ("--delay", String::from("20")),
+            ]
+        );
+        assert_eq!(
+            parse_args(args(&["--delay"]), &[], &["--delay"]),
+            Err(String::from("`--delay` needs a value"))
+        );
+        assert_eq!(
+            parse_args(args(&["--nope"]), &["--verbose"], &["--delay"]),
+            Err(String::from(
+                "Unknown argument `--nope`, expected `--input <path>` or `--verbose` or `--delay <value>`"
+            ))
+        );
+
+        let day_args = DayArgs {
+            input: String::new(),
+            flags: vec!["--verbose"],
+            options: parsed.options,
+        };
+        assert!(day_args.flag("--verbose"));
+        assert_eq!(day_args.option("--delay"), Some("20"));
+        assert_eq!(day_args.option("--obstacle"), None);
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(InputSource::Bundled.read("1 2 3").unwrap(), "1 2 3");
+    }
+
+    #[test]
+    fn missing_file() {
+        let error = InputSource::from_arg(Some("does/not/exist.txt"))
+            .read("")
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Couldn't read the input file `does/not/exist.txt`"));
+    }
+}
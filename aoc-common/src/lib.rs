@@ -0,0 +1,16 @@
+//! Shared helpers for the Advent of Code days.
+//! Anything that more than one day needs (grids, positions, directions, the [`Solution`] trait) lives here
+//! so the days don't have to copy and paste them around.
+
+mod direction;
+mod error;
+mod grid;
+pub mod input;
+mod position;
+mod solution;
+
+pub use direction::Direction;
+pub use error::{ParseError, ParseErrorKind};
+pub use grid::Grid;
+pub use position::Position;
+pub use solution::{catch_panic, Part, Solution};
@@ -0,0 +1,102 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::Direction;
+
+/// A point on a grid as `(x, y)`.  
+/// `x` grows to the right and `y` grows downwards, matching the way the puzzle input is read.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Position(pub i32, pub i32);
+
+impl Position {
+    /// The position one step away in the given direction.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+
+    /// The position `distance` steps away in the given direction.
+    pub fn offset(self, direction: Direction, distance: i32) -> Self {
+        self + direction.delta() * distance
+    }
+
+    /// The four orthogonal neighbours, in `Up`, `Right`, `Down`, `Left` order.
+    pub fn neighbours(self) -> [Position; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    /// All eight neighbours, clockwise starting from straight up.
+    pub fn neighbours_with_diagonals(self) -> [Position; 8] {
+        [
+            Position(0, -1),
+            Position(1, -1),
+            Position(1, 0),
+            Position(1, 1),
+            Position(0, 1),
+            Position(-1, 1),
+            Position(-1, 0),
+            Position(-1, -1),
+        ]
+        .map(|delta| self + delta)
+    }
+
+    /// Manhattan distance between two positions.
+    pub fn manhattan_distance(self, other: Self) -> i32 {
+        (self.0 - other.0).abs() + (self.1 - other.1).abs()
+    }
+}
+
+impl Add for Position {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl Sub for Position {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl Mul<i32> for Position {
+    type Output = Self;
+
+    fn mul(self, scale: i32) -> Self {
+        Self(self.0 * scale, self.1 * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Position(1, 2) + Position(3, 4), Position(4, 6));
+        assert_eq!(Position(1, 2) - Position(3, 4), Position(-2, -2));
+        assert_eq!(Position(1, -2) * 3, Position(3, -6));
+    }
+
+    #[test]
+    fn step_and_offset() {
+        assert_eq!(Position(4, 6).step(Direction::Up), Position(4, 5));
+        assert_eq!(Position(4, 6).offset(Direction::Left, 3), Position(1, 6));
+    }
+
+    #[test]
+    fn neighbours() {
+        assert_eq!(
+            Position(0, 0).neighbours(),
+            [
+                Position(0, -1),
+                Position(1, 0),
+                Position(0, 1),
+                Position(-1, 0)
+            ]
+        );
+        assert_eq!(Position(5, 5).neighbours_with_diagonals().len(), 8);
+        assert_eq!(Position(1, 1).manhattan_distance(Position(4, -1)), 5);
+    }
+}
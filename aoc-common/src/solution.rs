@@ -0,0 +1,99 @@
+use std::{
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::ParseError;
+
+/// One day of the calendar.  
+/// Parsing happens once and both parts work from the parsed input, so the runner can time each step on its own.
+pub trait Solution {
+    /// Day of the month this solves.
+    const DAY: u8;
+    /// The puzzle input that ships with the day.
+    const INPUT: &'static str;
+
+    /// Whatever the day parses its input into.
+    type Input;
+    /// The type of answer both parts give back.
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_1(input: &Self::Input) -> Self::Answer;
+
+    /// `None` until the second part has been solved.
+    fn part_2(_input: &Self::Input) -> Option<Self::Answer> {
+        None
+    }
+}
+
+/// Run a day's code, giving back the message instead if it panics, usually from a `todo!()` or a bad input.  
+/// The default hook is kept from printing the panic, so whoever runs the day decides how to report it.
+pub fn catch_panic<T>(solve: impl FnOnce() -> T) -> Result<T, String> {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(solve));
+    panic::set_hook(default_hook);
+
+    result.map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            String::from("unknown panic")
+        }
+    })
+}
+
+/// Which half of a day's puzzle to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("There is no part {}, only 1 or 2", value)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `pad` rather than `write!` so widths like `{:>4}` still apply.
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catch_panic_messages() {
+        assert_eq!(catch_panic(|| 1 + 1), Ok(2));
+        assert_eq!(
+            catch_panic(|| -> i32 { todo!() }),
+            Err(String::from("not yet implemented"))
+        );
+        assert_eq!(
+            catch_panic(|| -> i32 { panic!("day {}", 6) }),
+            Err(String::from("day 6"))
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use aoc_common::{catch_panic, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Benchmark parsing and each part of a day on its real input.
+/// Anything that doesn't work yet is skipped rather than stopping the whole run.
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let parsed = match S::parse(S::INPUT) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("Skipping day {}. {}", S::DAY, error);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day-{:02}", S::DAY));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(S::INPUT))));
+
+    if works(S::DAY, "part 1", || Some(S::part_1(&parsed))) {
+        group.bench_function("part 1", |b| b.iter(|| S::part_1(black_box(&parsed))));
+    }
+    if works(S::DAY, "part 2", || S::part_2(&parsed)) {
+        group.bench_function("part 2", |b| b.iter(|| S::part_2(black_box(&parsed))));
+    }
+
+    group.finish();
+}
+
+/// Run a part once to see if it's worth timing. `todo!()`s and unsolved parts aren't.
+fn works<T>(day: u8, part: &str, solve: impl FnOnce() -> Option<T>) -> bool {
+    match catch_panic(solve) {
+        Ok(Some(_)) => true,
+        Ok(None) => false,
+        Err(message) => {
+            eprintln!("Skipping day {} {}, it panicked: {}", day, part, message);
+            false
+        }
+    }
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day_01::Day01>(c);
+    bench_day::<day_02::Day02>(c);
+    bench_day::<day_03::Day03>(c);
+    bench_day::<day_04::Day04>(c);
+    bench_day::<day_05::Day05>(c);
+    bench_day::<day_06::Day06>(c);
+    bench_day::<day_07::Day07>(c);
+    bench_day::<day_08::Day08>(c);
+    bench_day::<day_09::Day09>(c);
+}
+
+criterion_group! {
+    name = benches;
+    // Day 9 part 1 takes most of a second a run, so the default 100 samples would take over a minute for it alone.
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
@@ -0,0 +1,339 @@
+use std::{
+    cmp::Ordering,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::Part;
+use serde::{Deserialize, Serialize};
+
+/// What the Advent of Code site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Wrong,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted for a day, kept in `day-XX/answers.toml`.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerHistory {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part_1: Vec<Submission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part_2: Vec<Submission>,
+    /// What each part gave on the real input last time it was checked.
+    /// Used by the regression check when there isn't a correct answer to go on.
+    #[serde(default, skip_serializing_if = "Expected::is_empty")]
+    pub expected: Expected,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Expected {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<String>,
+}
+
+impl Expected {
+    fn is_empty(&self) -> bool {
+        self.part_1.is_none() && self.part_2.is_none()
+    }
+}
+
+/// How a freshly computed answer lines up against the history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// Matches the accepted answer.
+    Correct,
+    /// The accepted answer is known and this isn't it.
+    Mismatch { expected: String },
+    /// Exactly an answer that was already rejected.
+    KnownWrong(Verdict),
+    /// At or above an answer that was too high.
+    TooHigh { bound: String },
+    /// At or below an answer that was too low.
+    TooLow { bound: String },
+    /// Nothing to compare against.
+    Unknown,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Correct => write!(f, "correct"),
+            Check::Mismatch { expected } => write!(f, "WRONG, expected {}", expected),
+            Check::KnownWrong(verdict) => write!(f, "WRONG, already submitted ({})", verdict),
+            Check::TooHigh { bound } => write!(f, "TOO HIGH, must be below {}", bound),
+            Check::TooLow { bound } => write!(f, "TOO LOW, must be above {}", bound),
+            Check::Unknown => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswerError {
+    Io(PathBuf, io::Error),
+    Read(PathBuf, toml::de::Error),
+    Write(PathBuf, toml::ser::Error),
+}
+
+impl fmt::Display for AnswerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerError::Io(path, error) => write!(f, "{}: {}", path.display(), error),
+            AnswerError::Read(path, error) => {
+                write!(f, "Couldn't read {}: {}", path.display(), error)
+            }
+            AnswerError::Write(path, error) => {
+                write!(f, "Couldn't write {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswerError {}
+
+/// Where a day's answer history lives.
+pub fn history_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day-{:02}", day))
+        .join("answers.toml")
+}
+
+impl AnswerHistory {
+    /// Load the history from `path`. A day without a file just has no history yet.
+    pub fn load(path: &Path) -> Result<Self, AnswerError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                toml::from_str(&contents).map_err(|error| AnswerError::Read(path.to_owned(), error))
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(AnswerError::Io(path.to_owned(), error)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswerError> {
+        let contents =
+            toml::to_string(self).map_err(|error| AnswerError::Write(path.to_owned(), error))?;
+        fs::write(path, contents).map_err(|error| AnswerError::Io(path.to_owned(), error))
+    }
+
+    pub fn submissions(&self, part: Part) -> &[Submission] {
+        match part {
+            Part::One => &self.part_1,
+            Part::Two => &self.part_2,
+        }
+    }
+
+    /// The answer a part should give on the real input.  
+    /// An answer the site said was correct wins over the recorded `expected` one.
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        let expected = match part {
+            Part::One => self.expected.part_1.as_deref(),
+            Part::Two => self.expected.part_2.as_deref(),
+        };
+
+        self.correct(part).or(expected)
+    }
+
+    /// The answer the site accepted, if there is one.
+    pub fn correct(&self, part: Part) -> Option<&str> {
+        self.submissions(part)
+            .iter()
+            .find(|submission| submission.verdict == Verdict::Correct)
+            .map(|submission| submission.answer.as_str())
+    }
+
+    pub fn set_expected(&mut self, part: Part, answer: &str) {
+        let expected = match part {
+            Part::One => &mut self.expected.part_1,
+            Part::Two => &mut self.expected.part_2,
+        };
+        *expected = Some(answer.to_string());
+    }
+
+    /// Record a submission. Submitting the same answer again replaces its old verdict.
+    pub fn record(&mut self, part: Part, answer: &str, verdict: Verdict) {
+        let submissions = match part {
+            Part::One => &mut self.part_1,
+            Part::Two => &mut self.part_2,
+        };
+        submissions.retain(|submission| submission.answer != answer);
+        submissions.push(Submission {
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Check {
+        let submissions = self.submissions(part);
+
+        if let Some(correct) = self.correct(part) {
+            if correct == answer {
+                return Check::Correct;
+            }
+            return Check::Mismatch {
+                expected: correct.to_string(),
+            };
+        }
+
+        if let Some(submission) = submissions
+            .iter()
+            .find(|submission| submission.answer == answer)
+        {
+            return Check::KnownWrong(submission.verdict);
+        }
+
+        // The lowest too high and the highest too low answers are the tightest bounds.
+        let lowest_too_high = submissions
+            .iter()
+            .filter(|submission| submission.verdict == Verdict::TooHigh)
+            .map(|submission| submission.answer.as_str())
+            .min_by(|a, b| compare_numbers(a, b));
+        if let Some(bound) = lowest_too_high {
+            if compare_numbers(answer, bound) != Ordering::Less {
+                return Check::TooHigh {
+                    bound: bound.to_string(),
+                };
+            }
+        }
+
+        let highest_too_low = submissions
+            .iter()
+            .filter(|submission| submission.verdict == Verdict::TooLow)
+            .map(|submission| submission.answer.as_str())
+            .max_by(|a, b| compare_numbers(a, b));
+        if let Some(bound) = highest_too_low {
+            if compare_numbers(answer, bound) != Ordering::Greater {
+                return Check::TooLow {
+                    bound: bound.to_string(),
+                };
+            }
+        }
+
+        Check::Unknown
+    }
+}
+
+/// Answers are kept as text, but bounds only make sense when comparing them as numbers.
+fn compare_numbers(a: &str, b: &str) -> Ordering {
+    match (a.parse::<i128>(), b.parse::<i128>()) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> AnswerHistory {
+        let mut history = AnswerHistory::default();
+        history.record(Part::Two, "402", Verdict::TooLow);
+        history.record(Part::Two, "404", Verdict::Wrong);
+        history.record(Part::Two, "440", Verdict::TooHigh);
+        history.record(Part::Two, "649", Verdict::TooHigh);
+        history
+    }
+
+    #[test]
+    fn check_against_bounds() {
+        let history = history();
+        assert_eq!(history.check(Part::Two, "428"), Check::Unknown);
+        assert_eq!(
+            history.check(Part::Two, "404"),
+            Check::KnownWrong(Verdict::Wrong)
+        );
+        assert_eq!(
+            history.check(Part::Two, "500"),
+            Check::TooHigh {
+                bound: String::from("440")
+            }
+        );
+        assert_eq!(
+            history.check(Part::Two, "99"),
+            Check::TooLow {
+                bound: String::from("402")
+            }
+        );
+        assert_eq!(history.check(Part::One, "1"), Check::Unknown);
+    }
+
+    #[test]
+    fn check_against_correct() {
+        let mut history = history();
+        history.record(Part::Two, "428", Verdict::Correct);
+        assert_eq!(history.check(Part::Two, "428"), Check::Correct);
+        assert_eq!(
+            history.check(Part::Two, "430"),
+            Check::Mismatch {
+                expected: String::from("428")
+            }
+        );
+    }
+
+    #[test]
+    fn record_replaces_verdict() {
+        let mut history = AnswerHistory::default();
+        history.record(Part::One, "10", Verdict::Wrong);
+        history.record(Part::One, "10", Verdict::TooLow);
+        assert_eq!(
+            history.part_1,
+            vec![Submission {
+                answer: String::from("10"),
+                verdict: Verdict::TooLow
+            }]
+        );
+    }
+
+    #[test]
+    fn expected_prefers_correct() {
+        let mut history = history();
+        assert_eq!(history.expected(Part::Two), None);
+
+        history.set_expected(Part::Two, "427");
+        assert_eq!(history.expected(Part::Two), Some("427"));
+
+        history.record(Part::Two, "428", Verdict::Correct);
+        assert_eq!(history.expected(Part::Two), Some("428"));
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let mut history = history();
+        history.set_expected(Part::One, "11");
+        let contents = toml::to_string(&history).unwrap();
+        assert!(contents.contains("verdict = \"too_low\""));
+        assert_eq!(toml::from_str::<AnswerHistory>(&contents).unwrap(), history);
+    }
+
+    #[test]
+    fn recorded_histories_load() {
+        for day in crate::days::all() {
+            AnswerHistory::load(&history_path(day.number)).unwrap();
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use aoc_common::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    days::Day,
+    runner::{self, Outcome},
+};
+
+/// Stop sampling a part once it has used this much time, so slow days don't hold up the rest.
+const TIME_BUDGET: Duration = Duration::from_secs(5);
+
+/// The median timings of one part of one day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub day: u8,
+    pub part: u8,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+impl BenchResult {
+    pub fn total(&self) -> Duration {
+        Duration::from_nanos(self.parse_ns + self.solve_ns)
+    }
+}
+
+/// A set of results saved to disk, used as the baseline for the next run.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchResults {
+    #[serde(default, rename = "result")]
+    pub results: Vec<BenchResult>,
+}
+
+#[derive(Debug)]
+pub enum BenchError {
+    Io(PathBuf, io::Error),
+    Read(PathBuf, toml::de::Error),
+    Write(toml::ser::Error),
+}
+
+impl fmt::Display for BenchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BenchError::Io(path, error) => {
+                write!(f, "Couldn't access `{}`: {}", path.display(), error)
+            }
+            BenchError::Read(path, error) => {
+                write!(
+                    f,
+                    "Couldn't read the benchmark results in `{}`: {}",
+                    path.display(),
+                    error
+                )
+            }
+            BenchError::Write(error) => {
+                write!(f, "Couldn't write the benchmark results: {}", error)
+            }
+        }
+    }
+}
+
+/// How a part's time compares to the baseline.
+#[derive(Debug, PartialEq)]
+pub enum Comparison {
+    /// There is nothing in the baseline for this part.
+    New,
+    /// Within the threshold either way.
+    Same(f64),
+    Faster(f64),
+    Slower(f64),
+}
+
+impl Comparison {
+    /// Compare the total of parse and solve time, as a percentage change from the baseline.
+    pub fn new(result: &BenchResult, baseline: Option<&BenchResult>, threshold: f64) -> Self {
+        let Some(baseline) = baseline else {
+            return Comparison::New;
+        };
+
+        let before = baseline.total().as_secs_f64();
+        let after = result.total().as_secs_f64();
+        let change = if before == 0.0 {
+            0.0
+        } else {
+            (after - before) / before * 100.0
+        };
+
+        if change > threshold {
+            Comparison::Slower(change)
+        } else if change < -threshold {
+            Comparison::Faster(change)
+        } else {
+            Comparison::Same(change)
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Comparison::New => write!(f, "new"),
+            Comparison::Same(change) => write!(f, "{:+.1}%", change),
+            Comparison::Faster(change) => write!(f, "{:+.1}% faster", change),
+            Comparison::Slower(change) => write!(f, "{:+.1}% SLOWER", change),
+        }
+    }
+}
+
+/// Where the baseline is kept. It lives in `target` as timings only mean something on the machine that made them.
+pub fn baseline_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/aoc-bench/baseline.toml")
+}
+
+impl BenchResults {
+    /// Load saved results. A missing file is just an empty baseline.
+    pub fn load(path: &Path) -> Result<Self, BenchError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|error| BenchError::Read(path.to_path_buf(), error)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(BenchResults::default()),
+            Err(error) => Err(BenchError::Io(path.to_path_buf(), error)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BenchError> {
+        let contents = toml::to_string(self).map_err(BenchError::Write)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| BenchError::Io(parent.to_path_buf(), error))?;
+        }
+        fs::write(path, contents).map_err(|error| BenchError::Io(path.to_path_buf(), error))
+    }
+
+    pub fn find(&self, day: u8, part: u8) -> Option<&BenchResult> {
+        self.results
+            .iter()
+            .find(|result| result.day == day && result.part == part)
+    }
+
+    /// Replace the results for any day and part in `other`, keeping the rest.
+    pub fn merge(&mut self, other: BenchResults) {
+        for result in other.results {
+            match self
+                .results
+                .iter_mut()
+                .find(|existing| existing.day == result.day && existing.part == result.part)
+            {
+                Some(existing) => *existing = result,
+                None => self.results.push(result),
+            }
+        }
+        self.results.sort_by_key(|result| (result.day, result.part));
+    }
+}
+
+/// Time one part of a day over up to `samples` runs and keep the median of each step.
+/// Gives back the outcome instead when the part can't be run.
+pub fn bench(day: &Day, part: Part, samples: usize) -> Result<BenchResult, Outcome> {
+    // Make sure it works before timing it, so a panic is caught once rather than every sample.
+    match runner::run_day(day, day.input, part) {
+        Outcome::Solved(_) => (),
+        outcome => return Err(outcome),
+    }
+
+    let mut parse_times = Vec::with_capacity(samples);
+    let mut solve_times = Vec::with_capacity(samples);
+    let start = Instant::now();
+    while parse_times.len() < samples.max(1) {
+        let Ok(Some(part_run)) = (day.run)(day.input, part) else {
+            unreachable!("day {} part {} worked a moment ago", day.number, part);
+        };
+        parse_times.push(part_run.parse_time);
+        solve_times.push(part_run.solve_time);
+
+        if start.elapsed() > TIME_BUDGET {
+            break;
+        }
+    }
+
+    Ok(BenchResult {
+        day: day.number,
+        part: part_number(part),
+        parse_ns: median(&mut parse_times).as_nanos() as u64,
+        solve_ns: median(&mut solve_times).as_nanos() as u64,
+    })
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+fn median(times: &mut [Duration]) -> Duration {
+    times.sort();
+    times[times.len() / 2]
+}
+
+pub fn print_header() {
+    println!(
+        "{:>3}  {:>4}  {:>12}  {:>12}  {:>12}  Change",
+        "Day", "Part", "Parse", "Solve", "Baseline"
+    );
+}
+
+pub fn print_row(result: &BenchResult, baseline: Option<&BenchResult>, comparison: &Comparison) {
+    println!(
+        "{:>3}  {:>4}  {:>12}  {:>12}  {:>12}  {}",
+        result.day,
+        result.part,
+        format!("{:.2?}", Duration::from_nanos(result.parse_ns)),
+        format!("{:.2?}", Duration::from_nanos(result.solve_ns)),
+        baseline
+            .map(|baseline| format!("{:.2?}", baseline.total()))
+            .unwrap_or_default(),
+        comparison,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, part: u8, solve_ns: u64) -> BenchResult {
+        BenchResult {
+            day,
+            part,
+            parse_ns: 0,
+            solve_ns,
+        }
+    }
+
+    #[test]
+    fn compare_against_baseline() {
+        let baseline = result(6, 2, 1000);
+        assert_eq!(
+            Comparison::new(&result(6, 2, 1000), None, 10.0),
+            Comparison::New
+        );
+        // Compare the printed change, the percentages themselves aren't exact.
+        assert_eq!(
+            Comparison::new(&result(6, 2, 1050), Some(&baseline), 10.0).to_string(),
+            "+5.0%"
+        );
+        assert_eq!(
+            Comparison::new(&result(6, 2, 1500), Some(&baseline), 10.0).to_string(),
+            "+50.0% SLOWER"
+        );
+        assert_eq!(
+            Comparison::new(&result(6, 2, 500), Some(&baseline), 10.0).to_string(),
+            "-50.0% faster"
+        );
+    }
+
+    #[test]
+    fn merge_keeps_other_days() {
+        let mut baseline = BenchResults {
+            results: vec![result(1, 1, 10), result(6, 2, 1000)],
+        };
+        baseline.merge(BenchResults {
+            results: vec![result(6, 2, 900), result(5, 1, 50)],
+        });
+        assert_eq!(
+            baseline.results,
+            vec![result(1, 1, 10), result(5, 1, 50), result(6, 2, 900)]
+        );
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.toml", std::process::id()));
+        let results = BenchResults {
+            results: vec![result(1, 1, 10), result(1, 2, 20)],
+        };
+        results.save(&path).unwrap();
+        assert_eq!(BenchResults::load(&path).unwrap(), results);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn median_of_samples() {
+        let mut times = [3, 1, 2].map(Duration::from_nanos);
+        assert_eq!(median(&mut times), Duration::from_nanos(2));
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_common::{ParseError, Part, Solution};
+
+use crate::runner::{self, PartRun};
+
+/// A day with its types erased, so every day can sit in the same list.
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    pub run: fn(&str, Part) -> Result<Option<PartRun>, ParseError>,
+}
+
+impl Day {
+    fn new<S: Solution>() -> Self {
+        Day {
+            number: S::DAY,
+            input: S::INPUT,
+            run: runner::run::<S>,
+        }
+    }
+}
+
+/// Every day that has been started, in calendar order.
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day_01::Day01>(),
+        Day::new::<day_02::Day02>(),
+        Day::new::<day_03::Day03>(),
+        Day::new::<day_04::Day04>(),
+        Day::new::<day_05::Day05>(),
+        Day::new::<day_06::Day06>(),
+        Day::new::<day_07::Day07>(),
+        Day::new::<day_08::Day08>(),
+        Day::new::<day_09::Day09>(),
+    ]
+}
+
+pub fn find(number: u8) -> Option<Day> {
+    all().into_iter().find(|day| day.number == number)
+}
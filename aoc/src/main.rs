@@ -0,0 +1,355 @@
+use std::process::ExitCode;
+
+use answers::{AnswerHistory, Verdict};
+use aoc_common::{input::InputSource, Part};
+use bench::{BenchResults, Comparison};
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use regression::Regression;
+
+mod answers;
+mod bench;
+mod days;
+mod regression;
+mod runner;
+mod scaffold;
+
+/// Run the Advent of Code 2024 solutions.
+#[derive(Debug, Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run one day, or every day with `--all`, and print the answers with their timings.
+    Run(RunArgs),
+    /// Record an answer that was submitted and what the site said about it.
+    Answer(AnswerArgs),
+    /// Check every day against the answers it should give on its real input.
+    Check(CheckArgs),
+    /// Time every day on its real input and flag anything slower than the saved baseline.
+    Bench(BenchArgs),
+    /// Start a new day from the template and add it to the workspace and the runner.
+    New(NewArgs),
+}
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("days").required(true).args(["day", "all"])))]
+struct RunArgs {
+    /// The day to run.
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Only run this part of the day (1 or 2).
+    #[arg(short, long, requires = "day", conflicts_with = "all", value_parser = parse_part)]
+    part: Option<Part>,
+    /// Run every day.
+    #[arg(short, long)]
+    all: bool,
+    /// Read the input from this file, or `-` for stdin, instead of the day's bundled `input.txt`.
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct AnswerArgs {
+    #[arg(short, long)]
+    day: u8,
+    #[arg(short, long, value_parser = parse_part)]
+    part: Part,
+    /// What the site said about the answer.
+    #[arg(short, long, value_enum)]
+    verdict: Verdict,
+    /// The answer that was submitted.
+    answer: String,
+}
+
+#[derive(Debug, Args)]
+struct CheckArgs {
+    /// Only check this day.
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Record the current answers as the expected ones.  
+    /// Answers marked correct in the history are never replaced.
+    #[arg(long)]
+    update: bool,
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    /// Only time this day.
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// How many times to run each part. Slow parts stop early after a few seconds.
+    #[arg(short, long, default_value_t = 10)]
+    samples: usize,
+    /// How far, in percent, a part can drift from the baseline before it is flagged.
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+    /// Save these results as the new baseline.  
+    /// The first run is always saved.
+    #[arg(long)]
+    save: bool,
+}
+
+#[derive(Debug, Args)]
+struct NewArgs {
+    /// The day to create.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    let number = value
+        .parse::<u8>()
+        .map_err(|_| format!("{} isn't a part number", value))?;
+
+    Part::try_from(number)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Answer(args) => answer(args),
+        Command::Check(args) => check(args),
+        Command::Bench(args) => bench(args),
+        Command::New(args) => new(args),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let Some(days) = select_days(args.day) else {
+        return ExitCode::FAILURE;
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    // Read every input before printing anything so a missing file doesn't leave half a table behind.
+    let source = InputSource::from_arg(args.input.as_deref());
+    let mut inputs = vec![];
+    for day in &days {
+        match source.read(day.input) {
+            Ok(input) => inputs.push(input),
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    runner::print_header();
+    let mut failed = false;
+    for (day, input) in days.iter().zip(&inputs) {
+        // Past answers only mean something for the day's own input.
+        let history = match source {
+            InputSource::Bundled => load_history(day.number),
+            _ => None,
+        };
+
+        for part in &parts {
+            let outcome = runner::run_day(day, input, *part);
+            let check = match (&outcome, &history) {
+                (runner::Outcome::Solved(part_run), Some(history)) => {
+                    Some(history.check(*part, &part_run.answer))
+                }
+                _ => None,
+            };
+            runner::print_row(day, *part, &outcome, check.as_ref());
+            failed |= outcome.is_failure();
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// The one day asked for, or every day.
+fn select_days(day: Option<u8>) -> Option<Vec<days::Day>> {
+    match day {
+        Some(number) => match days::find(number) {
+            Some(day) => Some(vec![day]),
+            None => {
+                eprintln!("Day {} hasn't been started yet.", number);
+                None
+            }
+        },
+        None => Some(days::all()),
+    }
+}
+
+/// Load a day's answer history, warning rather than failing if it is broken.
+fn load_history(day: u8) -> Option<AnswerHistory> {
+    match AnswerHistory::load(&answers::history_path(day)) {
+        Ok(history) => Some(history),
+        Err(error) => {
+            eprintln!("Skipping answer checks for day {}. {}", day, error);
+            None
+        }
+    }
+}
+
+fn answer(args: AnswerArgs) -> ExitCode {
+    if days::find(args.day).is_none() {
+        eprintln!("Day {} hasn't been started yet.", args.day);
+        return ExitCode::FAILURE;
+    }
+
+    let path = answers::history_path(args.day);
+    let result = AnswerHistory::load(&path).and_then(|mut history| {
+        history.record(args.part, &args.answer, args.verdict);
+        history.save(&path)
+    });
+
+    match result {
+        Ok(()) => {
+            println!(
+                "Recorded {} as {} for day {} part {}.",
+                args.answer, args.verdict, args.day, args.part
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn check(args: CheckArgs) -> ExitCode {
+    let Some(days) = select_days(args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut failed = false;
+    for day in &days {
+        let path = answers::history_path(day.number);
+        let mut history = match AnswerHistory::load(&path) {
+            Ok(history) => history,
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let mut updated = false;
+        for part in Part::ALL {
+            let regression = regression::check(day, part, &history);
+            println!("{}", regression::report(day.number, part, &regression));
+
+            // A correct answer from the site is the truth, so `--update` never papers over drifting from it.
+            let can_update = args.update && history.correct(part).is_none();
+            match regression {
+                Regression::NoExpected { actual } | Regression::Drifted { actual, .. }
+                    if can_update =>
+                {
+                    println!("  Recorded {} as the expected answer", actual);
+                    history.set_expected(part, &actual);
+                    updated = true;
+                }
+                regression if regression.is_failure() => failed = true,
+                _ => (),
+            }
+        }
+
+        if updated {
+            if let Err(error) = history.save(&path) {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    let Some(days) = select_days(args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let path = bench::baseline_path();
+    let mut baseline = match BenchResults::load(&path) {
+        Ok(baseline) => baseline,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut results = BenchResults::default();
+    let mut slower = false;
+    bench::print_header();
+    for day in &days {
+        for part in Part::ALL {
+            match bench::bench(day, part, args.samples) {
+                Ok(result) => {
+                    let previous = baseline.find(result.day, result.part);
+                    let comparison = Comparison::new(&result, previous, args.threshold);
+                    slower |= matches!(comparison, Comparison::Slower(_));
+                    bench::print_row(&result, previous, &comparison);
+                    results.results.push(result);
+                }
+                // Nothing to time, but show why like `aoc run` does.
+                Err(outcome) => runner::print_row(day, part, &outcome, None),
+            }
+        }
+    }
+
+    if args.save || baseline.results.is_empty() {
+        baseline.merge(results);
+        if let Err(error) = baseline.save(&path) {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+        println!("Saved the baseline to {}", path.display());
+    }
+
+    if slower {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn new(args: NewArgs) -> ExitCode {
+    match scaffold::new_day(&scaffold::workspace_root(), args.day) {
+        Ok(folder) => {
+            println!("Created day {} in {}", args.day, folder.display());
+            println!("Paste the puzzle input into src/bin/input.txt and the example into SAMPLE.");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parses(args: &[&str]) -> bool {
+        Cli::try_parse_from(std::iter::once("aoc").chain(args.iter().copied())).is_ok()
+    }
+
+    #[test]
+    fn part_needs_a_day() {
+        assert!(parses(&["run", "--day", "6", "--part", "2"]));
+        assert!(parses(&["run", "--all"]));
+        assert!(!parses(&["run", "--part", "2"]));
+        assert!(!parses(&["run", "--all", "--part", "2"]));
+    }
+}
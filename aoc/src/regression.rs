@@ -0,0 +1,128 @@
+use aoc_common::Part;
+
+use crate::{
+    answers::AnswerHistory,
+    days::Day,
+    runner::{self, Outcome},
+};
+
+/// How one part did against the answer it is expected to give on the real input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Regression {
+    Matched,
+    Drifted {
+        expected: String,
+        actual: String,
+    },
+    /// Solved, but there is nothing recorded to compare it to.
+    NoExpected {
+        actual: String,
+    },
+    /// The day doesn't have this part yet.
+    Unsolved,
+    /// Parsing failed or the day panicked, but it never had an answer to lose.
+    Unfinished(String),
+    /// Parsing failed or the day panicked where it used to give an answer.
+    Failed(String),
+}
+
+impl Regression {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Regression::Drifted { .. } | Regression::Failed(_))
+    }
+}
+
+/// Run one part of a day on its bundled input and compare it to the history.
+pub fn check(day: &Day, part: Part, history: &AnswerHistory) -> Regression {
+    match runner::run_day(day, day.input, part) {
+        Outcome::Solved(part_run) => match history.expected(part) {
+            Some(expected) if expected == part_run.answer => Regression::Matched,
+            Some(expected) => Regression::Drifted {
+                expected: expected.to_string(),
+                actual: part_run.answer,
+            },
+            None => Regression::NoExpected {
+                actual: part_run.answer,
+            },
+        },
+        Outcome::Unsolved => Regression::Unsolved,
+        Outcome::ParseFailed(error) => failed(history, part, error.to_string()),
+        Outcome::Panicked(message) => failed(history, part, format!("panicked: {}", message)),
+    }
+}
+
+fn failed(history: &AnswerHistory, part: Part, message: String) -> Regression {
+    match history.expected(part) {
+        Some(_) => Regression::Failed(message),
+        None => Regression::Unfinished(message),
+    }
+}
+
+/// A line for the report, with the expected and actual answers diffed underneath when they drifted.
+pub fn report(day: u8, part: Part, regression: &Regression) -> String {
+    let prefix = format!("Day {} part {}", day, part);
+    match regression {
+        Regression::Matched => format!("{}: ok", prefix),
+        Regression::Drifted { expected, actual } => {
+            format!("{}: DRIFTED\n  - {}\n  + {}", prefix, expected, actual)
+        }
+        Regression::NoExpected { actual } => {
+            format!("{}: no expected answer recorded, got {}", prefix, actual)
+        }
+        Regression::Unsolved => format!("{}: not solved yet", prefix),
+        Regression::Unfinished(message) => format!("{}: not working yet, {}", prefix, message),
+        Regression::Failed(message) => format!("{}: FAILED, {}", prefix, message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::{self, Verdict};
+
+    #[test]
+    fn report_diff() {
+        let regression = Regression::Drifted {
+            expected: String::from("1586"),
+            actual: String::from("1590"),
+        };
+        assert_eq!(
+            report(6, Part::Two, &regression),
+            "Day 6 part 2: DRIFTED\n  - 1586\n  + 1590"
+        );
+        assert!(regression.is_failure());
+    }
+
+    #[test]
+    fn check_against_history() {
+        let day = crate::days::find(1).unwrap();
+        let mut history = AnswerHistory::default();
+        let Regression::NoExpected { actual } = check(&day, Part::One, &history) else {
+            panic!("Expected day 1 to solve without an expected answer");
+        };
+
+        history.set_expected(Part::One, &actual);
+        assert_eq!(check(&day, Part::One, &history), Regression::Matched);
+
+        history.record(Part::One, "1", Verdict::Correct);
+        assert!(check(&day, Part::One, &history).is_failure());
+    }
+
+    /// Runs every day against its real input, which is too slow for a debug build.
+    #[test]
+    #[ignore = "slow, run with `cargo test --release -p aoc -- --ignored`"]
+    fn real_inputs_match_expected_answers() {
+        let mut failures = vec![];
+        for day in crate::days::all() {
+            let history = AnswerHistory::load(&answers::history_path(day.number)).unwrap();
+            for part in Part::ALL {
+                let regression = check(&day, part, &history);
+                if regression.is_failure() {
+                    failures.push(report(day.number, part, &regression));
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+    }
+}
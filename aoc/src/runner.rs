@@ -0,0 +1,183 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::{catch_panic, ParseError, Part, Solution};
+
+use crate::{answers::Check, days::Day};
+
+/// The answer to one part along with how long it took to get there.
+#[derive(Debug)]
+pub struct PartRun {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// How running one part of a day went.
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(PartRun),
+    /// The day doesn't have this part yet.
+    Unsolved,
+    /// The input couldn't be parsed.
+    ParseFailed(ParseError),
+    /// The day panicked, usually a `todo!()` or a bad input.
+    Panicked(String),
+}
+
+impl Outcome {
+    /// Did the day break, rather than solve the part or not have it yet?
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::ParseFailed(_) | Outcome::Panicked(_))
+    }
+}
+
+/// Parse the input and solve one part, timing each step on its own.  
+/// Gives back `Ok(None)` when the day doesn't have that part yet.
+pub fn run<S: Solution>(input: &str, part: Part) -> Result<Option<PartRun>, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        Part::One => Some(S::part_1(&parsed)),
+        Part::Two => S::part_2(&parsed),
+    };
+    let solve_time = start.elapsed();
+
+    Ok(answer.map(|answer| PartRun {
+        answer: answer.to_string(),
+        parse_time,
+        solve_time,
+    }))
+}
+
+/// Run one part of a day, catching panics so one broken day doesn't stop the rest.
+pub fn run_day(day: &Day, input: &str, part: Part) -> Outcome {
+    // The panic message ends up in the table rather than being printed on its own.
+    match catch_panic(|| (day.run)(input, part)) {
+        Ok(Ok(Some(part_run))) => Outcome::Solved(part_run),
+        Ok(Ok(None)) => Outcome::Unsolved,
+        Ok(Err(error)) => Outcome::ParseFailed(error),
+        Err(message) => Outcome::Panicked(message),
+    }
+}
+
+pub fn print_header() {
+    println!(
+        "{:>3}  {:>4}  {:<20}  {:>12}  {:>12}  Check",
+        "Day", "Part", "Answer", "Parse", "Solve"
+    );
+}
+
+/// Print one row of the table. `check` is how the answer compares to the answer history, when there is one.
+pub fn print_row(day: &Day, part: Part, outcome: &Outcome, check: Option<&Check>) {
+    match outcome {
+        Outcome::Solved(part_run) => println!(
+            "{:>3}  {:>4}  {:<20}  {:>12}  {:>12}  {}",
+            day.number,
+            part,
+            part_run.answer,
+            format!("{:.2?}", part_run.parse_time),
+            format!("{:.2?}", part_run.solve_time),
+            check.map(Check::to_string).unwrap_or_default(),
+        ),
+        Outcome::Unsolved => println!("{:>3}  {:>4}  -", day.number, part),
+        Outcome::ParseFailed(error) => println!("{:>3}  {:>4}  {}", day.number, part, error),
+        Outcome::Panicked(message) => {
+            println!("{:>3}  {:>4}  panicked: {}", day.number, part, message)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::ParseErrorKind;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "1 2 3";
+
+        type Input = Vec<i32>;
+        type Answer = i32;
+
+        fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+            input
+                .split(' ')
+                .map(|n| {
+                    n.parse()
+                        .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, 1, 1, n))
+                })
+                .collect()
+        }
+
+        fn part_1(numbers: &Vec<i32>) -> i32 {
+            numbers.iter().sum()
+        }
+    }
+
+    /// Parses fine but always panics when solving.
+    struct Broken;
+
+    impl Solution for Broken {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+
+        type Input = ();
+        type Answer = i32;
+
+        fn parse(_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part_1(_input: &()) -> i32 {
+            todo!()
+        }
+    }
+
+    #[test]
+    fn run_part_1() {
+        let part_run = run::<Sum>(Sum::INPUT, Part::One).unwrap().unwrap();
+        assert_eq!(part_run.answer, "6");
+    }
+
+    #[test]
+    fn run_unsolved_part_2() {
+        assert!(run::<Sum>(Sum::INPUT, Part::Two).unwrap().is_none());
+    }
+
+    #[test]
+    fn run_day_outcomes() {
+        let day = Day {
+            number: 0,
+            input: Sum::INPUT,
+            run: run::<Sum>,
+        };
+        assert!(matches!(
+            run_day(&day, Sum::INPUT, Part::One),
+            Outcome::Solved(_)
+        ));
+        assert!(matches!(
+            run_day(&day, "1 x", Part::One),
+            Outcome::ParseFailed(_)
+        ));
+        assert!(!run_day(&day, Sum::INPUT, Part::One).is_failure());
+        assert!(!run_day(&day, Sum::INPUT, Part::Two).is_failure());
+        assert!(run_day(&day, "1 x", Part::One).is_failure());
+    }
+
+    #[test]
+    fn run_day_catches_panics() {
+        let day = Day {
+            number: 0,
+            input: Broken::INPUT,
+            run: run::<Broken>,
+        };
+        let outcome = run_day(&day, Broken::INPUT, Part::One);
+        assert!(matches!(outcome, Outcome::Panicked(_)));
+        assert!(outcome.is_failure());
+    }
+}
@@ -0,0 +1,268 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The files a new day starts with, relative to the day's folder.
+const TEMPLATES: [(&str, &str); 7] = [
+    ("Cargo.toml", include_str!("../template/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../template/lib.rs.tmpl")),
+    ("src/part1.rs", include_str!("../template/part1.rs.tmpl")),
+    ("src/part2.rs", include_str!("../template/part2.rs.tmpl")),
+    (
+        "src/bin/part1.rs",
+        include_str!("../template/bin_part1.rs.tmpl"),
+    ),
+    (
+        "src/bin/part2.rs",
+        include_str!("../template/bin_part2.rs.tmpl"),
+    ),
+    ("src/bin/input.txt", ""),
+];
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day already has a folder, and it's never overwritten.
+    Exists(PathBuf),
+    Io(PathBuf, io::Error),
+    /// A file that the day gets registered in doesn't look like expected.
+    Unrecognised(PathBuf, &'static str),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => {
+                write!(
+                    f,
+                    "`{}` already exists, not overwriting it.",
+                    path.display()
+                )
+            }
+            ScaffoldError::Io(path, error) => {
+                write!(f, "Couldn't access `{}`: {}", path.display(), error)
+            }
+            ScaffoldError::Unrecognised(path, what) => {
+                write!(f, "Couldn't find {} in `{}`.", what, path.display())
+            }
+        }
+    }
+}
+
+/// The root of the workspace, where the days live.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Create `day-XX` from the template and register it with the workspace, the `aoc` runner and its benchmarks.
+/// Every file is checked before anything is written, so a failure leaves the workspace as it was.
+pub fn new_day(root: &Path, day: u8) -> Result<PathBuf, ScaffoldError> {
+    let name = format!("day-{:02}", day);
+    let folder = root.join(&name);
+    if folder.exists() {
+        return Err(ScaffoldError::Exists(folder));
+    }
+
+    let workspace = root.join("Cargo.toml");
+    let workspace_contents = add_member(&read(&workspace)?, &name).ok_or(
+        ScaffoldError::Unrecognised(workspace.clone(), "the workspace `members`"),
+    )?;
+
+    let runner = root.join("aoc/Cargo.toml");
+    let runner_contents = insert_sorted(
+        &read(&runner)?,
+        "day-",
+        &format!("{} = {{ path = \"../{}\" }}", name, name),
+    )
+    .ok_or(ScaffoldError::Unrecognised(
+        runner.clone(),
+        "the day dependencies",
+    ))?;
+
+    let days = root.join("aoc/src/days.rs");
+    let days_contents = insert_sorted(
+        &read(&days)?,
+        "Day::new::<day_",
+        &format!("        Day::new::<day_{:02}::Day{:02}>(),", day, day),
+    )
+    .ok_or(ScaffoldError::Unrecognised(
+        days.clone(),
+        "the list of days",
+    ))?;
+
+    let benches = root.join("aoc/benches/days.rs");
+    let benches_contents = insert_sorted(
+        &read(&benches)?,
+        "bench_day::<day_",
+        &format!("    bench_day::<day_{:02}::Day{:02}>(c);", day, day),
+    )
+    .ok_or(ScaffoldError::Unrecognised(
+        benches.clone(),
+        "the benchmarked days",
+    ))?;
+
+    for (file, template) in TEMPLATES {
+        let path = folder.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| ScaffoldError::Io(parent.to_path_buf(), error))?;
+        }
+        write(&path, &render(template, day))?;
+    }
+    write(&workspace, &workspace_contents)?;
+    write(&runner, &runner_contents)?;
+    write(&days, &days_contents)?;
+    write(&benches, &benches_contents)?;
+
+    Ok(folder)
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|error| ScaffoldError::Io(path.to_path_buf(), error))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, contents).map_err(|error| ScaffoldError::Io(path.to_path_buf(), error))
+}
+
+/// Fill in the day. `{{day}}` is zero padded like the crate names, `{{number}}` isn't.
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{day}}", &format!("{:02}", day))
+        .replace("{{number}}", &day.to_string())
+}
+
+/// Add a crate to the workspace `members = [...]`, keeping them sorted.
+fn add_member(manifest: &str, member: &str) -> Option<String> {
+    let start = manifest.find("members = [")? + "members = [".len();
+    let end = start + manifest[start..].find(']')?;
+
+    let mut members: Vec<String> = manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().to_string())
+        .filter(|member| !member.is_empty())
+        .collect();
+    members.push(format!("\"{}\"", member));
+    members.sort();
+    members.dedup();
+
+    Some(format!(
+        "{}{}{}",
+        &manifest[..start],
+        members.join(", "),
+        &manifest[end..]
+    ))
+}
+
+/// Insert `line` amongst the lines starting with `marker`, keeping them sorted.
+/// Gives back `None` when there aren't any lines like that to go by.
+fn insert_sorted(text: &str, marker: &str, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let matching: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, existing)| existing.trim_start().starts_with(marker))
+        .map(|(i, _)| i)
+        .collect();
+
+    let index = matching
+        .iter()
+        .find(|i| lines[**i].trim_start() > line.trim_start())
+        .copied()
+        .unwrap_or(*matching.last()? + 1);
+    lines.insert(index, line);
+
+    let mut contents = lines.join("\n");
+    if text.ends_with('\n') {
+        contents.push('\n');
+    }
+    Some(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_workspace_member() {
+        let manifest =
+            "[workspace]\nmembers = [\"aoc\", \"day-01\", \"day-09\"]\nresolver = \"2\"\n";
+        assert_eq!(
+            add_member(manifest, "day-02").unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"day-01\", \"day-02\", \"day-09\"]\nresolver = \"2\"\n"
+        );
+        assert_eq!(add_member("[workspace]\n", "day-02"), None);
+    }
+
+    #[test]
+    fn insert_sorted_between_and_after() {
+        let text = "a = 1\nday-01 = 1\nday-03 = 1\nserde = 1\n";
+        assert_eq!(
+            insert_sorted(text, "day-", "day-02 = 1").unwrap(),
+            "a = 1\nday-01 = 1\nday-02 = 1\nday-03 = 1\nserde = 1\n"
+        );
+        assert_eq!(
+            insert_sorted(text, "day-", "day-10 = 1").unwrap(),
+            "a = 1\nday-01 = 1\nday-03 = 1\nday-10 = 1\nserde = 1\n"
+        );
+        assert_eq!(insert_sorted("a = 1\n", "day-", "day-10 = 1"), None);
+    }
+
+    #[test]
+    fn render_day_number() {
+        assert_eq!(
+            render("pub struct Day{{day}}; const DAY: u8 = {{number}};", 7),
+            "pub struct Day07; const DAY: u8 = 7;"
+        );
+    }
+
+    #[test]
+    fn new_day_refuses_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::create_dir_all(root.join("aoc/benches")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\", \"day-01\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\nday-01 = { path = \"../day-01\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "    vec![\n        Day::new::<day_01::Day01>(),\n    ]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/benches/days.rs"),
+            "fn days(c: &mut Criterion) {\n    bench_day::<day_01::Day01>(c);\n}\n",
+        )
+        .unwrap();
+
+        let folder = new_day(&root, 2).unwrap();
+        assert!(folder.join("src/bin/input.txt").exists());
+        let lib = fs::read_to_string(folder.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day02;"));
+        assert!(lib.contains("const DAY: u8 = 2;"));
+        // Part 2 stays on the trait's `None` until it's solved, rather than hitting its `todo!()`.
+        assert!(!lib.contains("fn part_2"));
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"day-01\", \"day-02\"]\n"
+        );
+        assert!(fs::read_to_string(root.join("aoc/src/days.rs"))
+            .unwrap()
+            .contains("Day::new::<day_02::Day02>(),"));
+        assert_eq!(
+            fs::read_to_string(root.join("aoc/benches/days.rs")).unwrap(),
+            "fn days(c: &mut Criterion) {\n    bench_day::<day_01::Day01>(c);\n    bench_day::<day_02::Day02>(c);\n}\n"
+        );
+
+        assert!(matches!(new_day(&root, 2), Err(ScaffoldError::Exists(_))));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
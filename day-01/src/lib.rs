@@ -0,0 +1,107 @@
+use aoc_common::{input::normalise, ParseError, ParseErrorKind, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// The two location id lists, each sorted smallest to largest.
+pub type Lists = (Vec<i32>, Vec<i32>);
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("./bin/input.txt");
+
+    type Input = Lists;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Lists, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(lists: &Lists) -> i32 {
+        part1::part_1(lists)
+    }
+
+    fn part_2(lists: &Lists) -> Option<i32> {
+        Some(part2::part_2(lists))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Lists, ParseError> {
+    let input = &*normalise(input);
+    let mut lists: Lists = (vec![], vec![]);
+    for (line, row) in input.split('\n').enumerate() {
+        let error =
+            |kind, column, text| ParseError::new(kind, line + 1, column, text).with_day(Day01::DAY);
+
+        let mut column = 1;
+        let mut columns_found = 0;
+        for (i, num) in row.split("   ").enumerate() {
+            if i > 1 {
+                return Err(error(
+                    ParseErrorKind::Expected("only two columns"),
+                    column,
+                    num,
+                ));
+            }
+
+            let value = num
+                .parse::<i32>()
+                .map_err(|_| error(ParseErrorKind::InvalidNumber, column, num))?;
+            match i {
+                0 => lists.0.push(value),
+                _ => lists.1.push(value),
+            }
+
+            column += num.chars().count() + 3;
+            columns_found += 1;
+        }
+
+        if columns_found < 2 {
+            return Err(error(
+                ParseErrorKind::Expected("two columns"),
+                row.chars().count() + 1,
+                "",
+            ));
+        }
+    }
+
+    lists.0.sort();
+    lists.1.sort();
+
+    Ok(lists)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_invalid_number() {
+        let error = parse("3   4\n4   x").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(ParseErrorKind::InvalidNumber, 2, 5, "x").with_day(1)
+        );
+    }
+
+    #[test]
+    fn parse_missing_column() {
+        let error = parse("3   4\n4").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::Expected("two columns"));
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn parse_trailing_newline_and_crlf() {
+        let sample = "3   4\n4   3\n2   5";
+        let expected = parse(sample).unwrap();
+
+        let with_newline = format!("{}\n", sample);
+        assert_eq!(parse(&with_newline).unwrap(), expected);
+
+        let with_crlf = format!("{}\r\n", sample.replace('\n', "\r\n"));
+        assert_eq!(parse(&with_crlf).unwrap(), expected);
+    }
+}
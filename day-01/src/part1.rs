@@ -0,0 +1,31 @@
+use std::iter::zip;
+
+use crate::Lists;
+
+pub fn part_1(lists: &Lists) -> i32 {
+    zip(&lists.0, &lists.1)
+        .map(|(a, b)| (a - b).abs())
+        .sum::<i32>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn part_1_sample() {
+        let result = part_1(
+            &parse(
+                "3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 11);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::Lists;
+
+pub fn part_2(lists: &Lists) -> i32 {
+    let mut acc = 0;
+    for val in &lists.0 {
+        let times_in_other_list = lists.1.iter().filter(|x| *x == val).count();
+
+        acc += val * times_in_other_list as i32;
+    }
+
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn part_2_sample() {
+        let result = part_2(
+            &parse(
+                "3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 31);
+    }
+}
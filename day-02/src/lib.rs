@@ -0,0 +1,77 @@
+use aoc_common::{input::normalise, ParseError, ParseErrorKind, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// The levels of a single report.
+pub type Report = Vec<i32>;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("./bin/input.txt");
+
+    type Input = Vec<Report>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Vec<Report>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(reports: &Vec<Report>) -> i32 {
+        part1::part_1(reports)
+    }
+
+    fn part_2(reports: &Vec<Report>) -> Option<i32> {
+        Some(part2::part_2(reports))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Report>, ParseError> {
+    let input = &*normalise(input);
+    input
+        .split('\n')
+        .enumerate()
+        .map(|(line, report)| {
+            let mut column = 1;
+            report
+                .split(' ')
+                .map(|level| {
+                    let parsed = level.parse::<i32>().map_err(|_| {
+                        ParseError::new(ParseErrorKind::InvalidNumber, line + 1, column, level)
+                            .with_day(Day02::DAY)
+                    });
+                    column += level.chars().count() + 1;
+                    parsed
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_invalid_level() {
+        let error = parse("7 6 4\n1 2 seven 8").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(ParseErrorKind::InvalidNumber, 2, 5, "seven").with_day(2)
+        );
+    }
+
+    #[test]
+    fn parse_trailing_newline_and_crlf() {
+        let sample = "7 6 4 2 1\n1 2 7 8 9";
+        let expected = parse(sample).unwrap();
+
+        let with_newline = format!("{}\n", sample);
+        assert_eq!(parse(&with_newline).unwrap(), expected);
+
+        let with_crlf = format!("{}\r\n", sample.replace('\n', "\r\n"));
+        assert_eq!(parse(&with_crlf).unwrap(), expected);
+    }
+}
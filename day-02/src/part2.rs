@@ -0,0 +1,69 @@
+use crate::{part1, Report};
+
+pub fn part_2(reports: &[Report]) -> i32 {
+    reports.iter().fold(0, |total_safe_reports, levels| {
+        if test_report(levels) {
+            total_safe_reports + 1
+        } else {
+            total_safe_reports
+        }
+    })
+}
+
+fn test_report(levels: &[i32]) -> bool {
+    // Safe without needing the problem dampener.
+    if part1::test_report(levels) {
+        return true;
+    }
+
+    // The problem dampener can remove any one level.
+    // Knowing which level is the bad one is harder than it looks, so try removing each of them.
+    (0..levels.len()).any(|index| {
+        let mut dampened_levels = levels.to_vec();
+        dampened_levels.remove(index);
+
+        part1::test_report(&dampened_levels)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn part_2_sample() {
+        let result = part_2(
+            &parse(
+                "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn part_2_reddit() {
+        let result = part_2(
+            &parse(
+                "48 46 47 49 51 54 56
+1 1 2 3 4 5
+1 2 3 4 5 5
+5 1 2 3 4 5
+1 4 3 2 1
+1 6 7 8 9
+1 2 3 4 3
+9 8 7 6 7
+7 10 8 10 11
+29 28 27 25 26 25 22 20",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 10);
+    }
+}
@@ -0,0 +1,117 @@
+use aoc_common::{input::normalise, ParseError, ParseErrorKind, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, anychar},
+    combinator::value,
+    multi::{many1, many_till},
+    sequence::{delimited, separated_pair},
+    IResult, Parser,
+};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("./bin/input.txt");
+
+    type Input = Vec<Instruction>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(instructions: &Vec<Instruction>) -> u32 {
+        part1::part_1(instructions)
+    }
+
+    fn part_2(instructions: &Vec<Instruction>) -> Option<u32> {
+        Some(part2::part_2(instructions))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+fn instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        value(Instruction::Dont, tag("don't()")),
+        value(Instruction::Do, tag("do()")),
+        mul,
+    ))(input)
+}
+
+fn mul(input: &str) -> IResult<&str, Instruction> {
+    let (input, _) = tag("mul")(input)?;
+    let (input, (x, y)) = delimited(tag("("), parse_numbers, tag(")"))(input)?;
+
+    Ok((input, Instruction::Mul(x, y)))
+}
+
+fn parse_numbers(input: &str) -> IResult<&str, (u32, u32)> {
+    separated_pair(complete::u32, tag(","), complete::u32)(input)
+}
+
+fn instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
+    many1(many_till(anychar, instruction).map(|(_, instruction)| instruction))(input)
+}
+
+/// Pull every instruction out of the corrupted memory, skipping over the junk in between.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let input = &*normalise(input);
+    // Junk is skipped, so the only way to fail is to never find an instruction at all.
+    let (_, instructions) = instructions(input).map_err(|_| {
+        ParseError::at_end(
+            input,
+            ParseErrorKind::Missing("a `mul`, `do()` or `don't()` instruction"),
+        )
+        .with_day(Day03::DAY)
+    })?;
+
+    Ok(instructions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_numbers_test() {
+        let (_, result) = parse_numbers("2,4").unwrap();
+        assert_eq!(result, (2, 4));
+    }
+    #[test]
+    fn parse_mul_test() {
+        let (_, result) = mul("mul(2,4)").unwrap();
+        assert_eq!(result, Instruction::Mul(2, 4));
+    }
+    #[test]
+    fn parse_without_instructions() {
+        let error = parse("mul(1,\nxyz").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Missing("a `mul`, `do()` or `don't()` instruction")
+        );
+        assert_eq!((error.day, error.line, error.column), (Some(3), 2, 4));
+    }
+
+    #[test]
+    fn parse_trailing_newline_and_crlf() {
+        let sample = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)\n+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let expected = parse(sample).unwrap();
+
+        let with_newline = format!("{}\n", sample);
+        assert_eq!(parse(&with_newline).unwrap(), expected);
+
+        let with_crlf = format!("{}\r\n", sample.replace('\n', "\r\n"));
+        assert_eq!(parse(&with_crlf).unwrap(), expected);
+    }
+}
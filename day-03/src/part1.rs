@@ -0,0 +1,26 @@
+use crate::Instruction;
+
+/// Every `mul` counts in part 1, the `do()` and `don't()` instructions are ignored.
+pub fn part_1(instructions: &[Instruction]) -> u32 {
+    instructions
+        .iter()
+        .fold(0, |acc, instruction| match instruction {
+            Instruction::Mul(x, y) => acc + (x * y),
+            _ => acc,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn part_1_sample() {
+        let instructions =
+            parse("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))")
+                .unwrap();
+        let result = part_1(&instructions);
+        assert_eq!(result, 161);
+    }
+}
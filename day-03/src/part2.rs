@@ -0,0 +1,37 @@
+use crate::Instruction;
+
+// This part was a walk through from Chris Biscardi (https://www.youtube.com/watch?v=Ja7nETLnsXQ)
+pub fn part_2(instructions: &[Instruction]) -> u32 {
+    instructions
+        .iter()
+        .fold(
+            (Instruction::Do, 0),
+            |(last_instruction, acc), instruction| match *instruction {
+                Instruction::Mul(x, y) => {
+                    if last_instruction == Instruction::Do {
+                        return (last_instruction, acc + (x * y));
+                    }
+
+                    (last_instruction, acc)
+                }
+                Instruction::Do => (Instruction::Do, acc),
+                Instruction::Dont => (Instruction::Dont, acc),
+            },
+        )
+        .1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn part_2_sample() {
+        let instructions =
+            parse("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))")
+                .unwrap();
+        let result = part_2(&instructions);
+        assert_eq!(result, 48);
+    }
+}
@@ -1,20 +1,8 @@
+use aoc_common::input;
+use day_04::{parse, part2::part_2};
+
 fn main() {
-    let input = include_str!("./input.txt");
-    let output = part_2(input);
+    let input = input::from_args(include_str!("./input.txt"), &[], &[]).input;
+    let output = part_2(&input::or_exit(parse(&input)));
     dbg!(output);
 }
-
-fn part_2(input: &str) -> i32 {
-    todo!()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part_2_sample() {
-        let result = 4;
-        assert_eq!(result, 4);
-    }
-}
@@ -0,0 +1,63 @@
+use aoc_common::{input::normalise, Grid, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod template;
+pub mod word_search;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("./bin/input.txt");
+
+    type Input = Grid<char>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(grid: &Grid<char>) -> i32 {
+        part1::part_1(grid)
+    }
+
+    fn part_2(grid: &Grid<char>) -> Option<i32> {
+        Some(part2::part_2(grid))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    let input = &*normalise(input);
+    Grid::try_from(input).map_err(|error| error.with_day(Day04::DAY))
+}
+
+/// The sample from the puzzle, shared between both parts' tests.
+#[cfg(test)]
+pub(crate) const SAMPLE: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_trailing_newline_and_crlf() {
+        let sample = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM";
+        let expected = parse(sample).unwrap();
+
+        let with_newline = format!("{}\n", sample);
+        assert_eq!(parse(&with_newline).unwrap(), expected);
+
+        let with_crlf = format!("{}\r\n", sample.replace('\n', "\r\n"));
+        assert_eq!(parse(&with_crlf).unwrap(), expected);
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_common::Grid;
+
+use crate::word_search::{find_word, Directions};
+
+pub fn part_1(grid: &Grid<char>) -> i32 {
+    find_word(grid, "XMAS", Directions::All).len() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    #[test]
+    fn part_1_sample() {
+        let result = part_1(&parse(SAMPLE).unwrap());
+        assert_eq!(result, 18);
+    }
+}
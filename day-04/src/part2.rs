@@ -0,0 +1,23 @@
+use aoc_common::Grid;
+
+use crate::template::Template;
+
+/// Two `MAS` crossing in an X, each of them either way round.
+const X_MAS: &str = "M.S/.A./M.S";
+
+pub fn part_2(grid: &Grid<char>) -> i32 {
+    let template = Template::parse(X_MAS).expect("the X-MAS template is valid");
+    template.find(grid).len() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    #[test]
+    fn part_2_sample() {
+        let result = part_2(&parse(SAMPLE).unwrap());
+        assert_eq!(result, 9);
+    }
+}
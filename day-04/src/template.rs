@@ -0,0 +1,141 @@
+use std::fmt;
+
+use aoc_common::{Grid, ParseError, ParseErrorKind, Position};
+
+/// A small 2D pattern to look for in the grid, written row by row with `/` between the rows.
+/// `.` matches any letter, so `M.S/.A./M.S` is two `MAS` crossing in an X.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template(Grid<Option<char>>);
+
+/// Where a template was found.
+/// `anchor` is the top left corner of the template in the orientation that matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateMatch {
+    pub anchor: Position,
+    pub orientation: Template,
+}
+
+impl Template {
+    pub fn parse(pattern: &str) -> Result<Self, ParseError> {
+        if pattern.is_empty() {
+            return Err(ParseError::new(
+                ParseErrorKind::Missing("a pattern"),
+                1,
+                1,
+                "",
+            ));
+        }
+
+        Grid::parse(&pattern.replace('/', "\n"), |c| (c != '.').then_some(c)).map(Template)
+    }
+
+    /// Every distinct way the template can be turned or flipped over, starting with itself.
+    pub fn orientations(&self) -> Vec<Template> {
+        let mut orientations: Vec<Template> = vec![];
+        let mut turned = self.0.clone();
+        for _ in 0..4 {
+            for grid in [turned.clone(), turned.flip_horizontal()] {
+                let template = Template(grid);
+                if !orientations.contains(&template) {
+                    orientations.push(template);
+                }
+            }
+            turned = turned.rotate_clockwise();
+        }
+
+        orientations
+    }
+
+    /// Does the template, as it is, fit the grid with its top left corner at `anchor`?
+    fn matches_at(&self, grid: &Grid<char>, anchor: Position) -> bool {
+        let far_corner = anchor + Position(self.0.width() as i32 - 1, self.0.height() as i32 - 1);
+        if !grid.in_bounds(anchor) || !grid.in_bounds(far_corner) {
+            return false;
+        }
+
+        self.0.iter().all(|(offset, cell)| match cell {
+            Some(letter) => grid.get(anchor + offset) == Some(letter),
+            None => true,
+        })
+    }
+
+    /// Find the template anywhere in the grid, in any orientation.
+    /// Orientations that look the same are only tried once, so a symmetric template isn't counted twice.
+    pub fn find(&self, grid: &Grid<char>) -> Vec<TemplateMatch> {
+        let orientations = self.orientations();
+
+        grid.positions()
+            .flat_map(|anchor| {
+                orientations
+                    .iter()
+                    .filter(move |orientation| orientation.matches_at(grid, anchor))
+                    .map(move |orientation| TemplateMatch {
+                        anchor,
+                        orientation: orientation.clone(),
+                    })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let grid = self.0.map(|cell| cell.unwrap_or('.'));
+        write!(f, "{}", grid.to_string().replace('\n', "/"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn x_mas_orientations() {
+        let template = Template::parse("M.S/.A./M.S").unwrap();
+        let orientations: Vec<String> = template
+            .orientations()
+            .iter()
+            .map(Template::to_string)
+            .collect();
+        assert_eq!(
+            orientations,
+            vec!["M.S/.A./M.S", "S.M/.A./S.M", "M.M/.A./S.S", "S.S/.A./M.M"]
+        );
+    }
+
+    #[test]
+    fn find_with_anchors() {
+        let grid = parse("M.S.\n.A..\nM.S.\n.A..\nM.S.").unwrap();
+        let matches = Template::parse("M.S/.A./M.S").unwrap().find(&grid);
+        let found: Vec<(Position, String)> = matches
+            .iter()
+            .map(|found| (found.anchor, found.orientation.to_string()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Position(0, 0), String::from("M.S/.A./M.S")),
+                (Position(0, 2), String::from("M.S/.A./M.S")),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_non_square_template() {
+        let grid = parse("AB..\n..B.\n..A.").unwrap();
+        let anchors: Vec<Position> = Template::parse("AB")
+            .unwrap()
+            .find(&grid)
+            .iter()
+            .map(|found| found.anchor)
+            .collect();
+        assert_eq!(anchors, vec![Position(0, 0), Position(2, 1)]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(Template::parse("").is_err());
+        assert!(Template::parse("M.S/.A").is_err());
+    }
+}
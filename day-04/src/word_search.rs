@@ -0,0 +1,115 @@
+use aoc_common::{Direction, Grid, Position};
+
+/// Which ways a word is allowed to run through the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Directions {
+    /// All eight, like a normal word search.
+    All,
+    Orthogonal,
+    Diagonal,
+}
+
+impl Directions {
+    /// The step from one letter to the next for each allowed direction.
+    /// Orthogonal steps go clockwise from up, then the diagonals go clockwise from up-right.
+    pub fn steps(self) -> Vec<Position> {
+        let orthogonal = Direction::ALL.map(Direction::delta);
+        // Each diagonal is a direction and the one clockwise from it together.
+        let diagonal =
+            Direction::ALL.map(|direction| direction.delta() + direction.turn_right().delta());
+
+        match self {
+            Directions::All => orthogonal.into_iter().chain(diagonal).collect(),
+            Directions::Orthogonal => orthogonal.to_vec(),
+            Directions::Diagonal => diagonal.to_vec(),
+        }
+    }
+}
+
+/// Where a word was found: its first letter and the step to each following letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WordMatch {
+    pub start: Position,
+    pub step: Position,
+}
+
+impl WordMatch {
+    /// The position of each letter of a word `length` letters long.
+    pub fn positions(self, length: usize) -> impl Iterator<Item = Position> {
+        (0..length as i32).map(move |i| self.start + self.step * i)
+    }
+}
+
+/// Find every occurrence of `word` running in any of the allowed directions.
+/// A word that reads the same backwards, like `ABA`, is found once from each end.
+pub fn find_word(grid: &Grid<char>, word: &str, directions: Directions) -> Vec<WordMatch> {
+    let letters: Vec<char> = word.chars().collect();
+    let Some(first) = letters.first() else {
+        return vec![];
+    };
+    let steps = directions.steps();
+
+    grid.positions_of(first)
+        .flat_map(|start| steps.iter().map(move |&step| WordMatch { start, step }))
+        .filter(|word_match| {
+            word_match
+                .positions(letters.len())
+                .zip(&letters)
+                .all(|(position, letter)| grid.get(position) == Some(letter))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    #[test]
+    fn steps_are_the_neighbours() {
+        let mut steps = Directions::All.steps();
+        let mut neighbours = Position(0, 0).neighbours_with_diagonals().to_vec();
+        steps.sort();
+        neighbours.sort();
+        assert_eq!(steps, neighbours);
+        assert_eq!(Directions::Diagonal.steps()[0], Position(1, -1));
+    }
+
+    #[test]
+    fn find_in_every_direction() {
+        let grid = parse("XMAS\nMM..\nA.A.\nS..S").unwrap();
+        let matches = find_word(&grid, "XMAS", Directions::All);
+        let steps: Vec<Position> = matches.iter().map(|word_match| word_match.step).collect();
+        assert_eq!(steps, vec![Position(1, 0), Position(0, 1), Position(1, 1)]);
+        assert_eq!(
+            matches[2].positions(4).collect::<Vec<_>>(),
+            vec![
+                Position(0, 0),
+                Position(1, 1),
+                Position(2, 2),
+                Position(3, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn find_with_direction_sets() {
+        let grid = parse(SAMPLE).unwrap();
+        let orthogonal = find_word(&grid, "XMAS", Directions::Orthogonal).len();
+        let diagonal = find_word(&grid, "XMAS", Directions::Diagonal).len();
+        assert_eq!(orthogonal, 8);
+        assert_eq!(diagonal, 10);
+        assert_eq!(
+            find_word(&grid, "XMAS", Directions::All).len(),
+            orthogonal + diagonal
+        );
+    }
+
+    #[test]
+    fn find_other_words() {
+        let grid = parse("ABA\nBAB").unwrap();
+        assert_eq!(find_word(&grid, "ABA", Directions::Orthogonal).len(), 2);
+        assert_eq!(find_word(&grid, "AB", Directions::All).len(), 7);
+        assert!(find_word(&grid, "", Directions::All).is_empty());
+    }
+}
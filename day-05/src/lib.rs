@@ -0,0 +1,151 @@
+use aoc_common::{input::normalise, ParseError, ParseErrorKind, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    multi::{separated_list0, separated_list1},
+    sequence::separated_pair,
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+
+/// Page `.0` has to be printed at some point before page `.1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortRule(pub i32, pub i32);
+
+/// The page ordering rules and the list of updates to check against them.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PrintQueue {
+    pub rules: Vec<SortRule>,
+    pub updates: Vec<Vec<i32>>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("./bin/input.txt");
+
+    type Input = PrintQueue;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<PrintQueue, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(queue: &PrintQueue) -> i32 {
+        part1::part_1(queue)
+    }
+
+    fn part_2(queue: &PrintQueue) -> Option<i32> {
+        Some(part2::part_2(queue))
+    }
+}
+
+pub fn parse(input: &str) -> Result<PrintQueue, ParseError> {
+    let input = &*normalise(input);
+    let error_at = |rest: &str, kind| {
+        // The parsers stop just before the newline ahead of the line that broke, so point at that line instead.
+        let rest = rest.strip_prefix('\n').unwrap_or(rest);
+        ParseError::at(input, rest, kind).with_day(Day05::DAY)
+    };
+
+    let (rest, rules) = separated_list0(tag("\n"), parse_sort_rule)(input).map_err(|error| {
+        ParseError::from_nom(input, error, ParseErrorKind::Expected("a `page|page` rule"))
+            .with_day(Day05::DAY)
+    })?;
+    let (rest, _) = tag("\n\n")(rest).map_err(|_: nom::Err<nom::error::Error<&str>>| {
+        error_at(
+            rest,
+            ParseErrorKind::Expected("a `page|page` rule or a blank line before the updates"),
+        )
+    })?;
+    let (rest, updates) = separated_list0(tag("\n"), parse_updates)(rest).map_err(|error| {
+        ParseError::from_nom(input, error, ParseErrorKind::Expected("an update"))
+            .with_day(Day05::DAY)
+    })?;
+
+    if !rest.is_empty() {
+        return Err(error_at(
+            rest,
+            ParseErrorKind::Expected("an update of comma separated pages"),
+        ));
+    }
+
+    Ok(PrintQueue { rules, updates })
+}
+
+fn parse_sort_rule(input: &str) -> IResult<&str, SortRule> {
+    let (input, (x, y)) = separated_pair(complete::i32, tag("|"), complete::i32)(input)?;
+    Ok((input, SortRule(x, y)))
+}
+
+fn parse_updates(input: &str) -> IResult<&str, Vec<i32>> {
+    separated_list1(tag(","), complete::i32)(input)
+}
+
+/// The sample from the puzzle, shared between both parts' tests.
+#[cfg(test)]
+pub(crate) const SAMPLE: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bad_rule() {
+        let error = parse("47|53\n97|x\n\n75,47").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (Some(5), 2, 1));
+        assert_eq!(error.text, "97|x");
+    }
+
+    #[test]
+    fn parse_bad_update() {
+        let error = parse("47|53\n\n75,47\n75;47").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 3));
+        assert_eq!(error.text, ";47");
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expected("an update of comma separated pages")
+        );
+    }
+
+    #[test]
+    fn parse_trailing_newline_and_crlf() {
+        let expected = parse(SAMPLE).unwrap();
+
+        let with_newline = format!("{}\n", SAMPLE);
+        assert_eq!(parse(&with_newline).unwrap(), expected);
+
+        let with_crlf = format!("{}\r\n", SAMPLE.replace('\n', "\r\n"));
+        assert_eq!(parse(&with_crlf).unwrap(), expected);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{PrintQueue, SortRule};
+
+pub fn part_1(queue: &PrintQueue) -> i32 {
+    let PrintQueue { rules, updates } = queue;
+    // dbg!(&rules);
+
+    // Make sure each update is in order and keep a sum of the middle pages.
+    updates.iter().fold(0, |acc, update| {
+        // Find valid rules
+        let rules_for_update: Vec<SortRule> = rules
+            .clone()
+            .into_iter()
+            .filter(|rule| update.contains(&rule.0) && update.contains(&rule.1))
+            .collect();
+
+        // Check all rules to make sure the update is good for each.
+        for rule in rules_for_update {
+            let index = update
+                .iter()
+                .position(|x| *x == rule.0)
+                .expect("Failed to find index of rule page 0");
+
+            let mut left_split = update.clone();
+            let right_split = left_split.split_off(index);
+
+            // This would mean we failed a rule and thus the entire update.
+            if !right_split.contains(&rule.1) {
+                return acc;
+            }
+        }
+
+        // println!("Update passed: {:?}", update);
+
+        // If we passed all of that, get the middle page.
+        let middle_page_index = update.len() / 2;
+        if let Some(page) = update.get(middle_page_index) {
+            // println!("Middle page number: {}", page);
+            acc + page
+        } else {
+            println!("Failed to get page: {}", middle_page_index);
+            acc
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    #[test]
+    fn part_1_sample() {
+        let result = part_1(&parse(SAMPLE).unwrap());
+        assert_eq!(result, 143);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{PrintQueue, SortRule};
+
+pub fn part_2(queue: &PrintQueue) -> i32 {
+    let PrintQueue { rules, updates } = queue;
+
+    // Make sure each update is in order and keep a sum of the middle pages.
+    updates.iter().fold(0, |acc, update| {
+        // Find valid rules
+        let rules_for_update: Vec<SortRule> = rules
+            .clone()
+            .into_iter()
+            .filter(|rule| update.contains(&rule.0) && update.contains(&rule.1))
+            .collect();
+
+        // Make sure we only work on the updates that are incorrect.
+        if !check_update_passed(update, &rules_for_update) {
+            // Sort the update based on the rules.
+            let update = sort_update(update.clone(), &rules_for_update);
+
+            // Get the middle page.
+            let middle_page_index = update.len() / 2;
+            if let Some(page) = update.get(middle_page_index) {
+                // println!("Middle page number: {}", page);
+                acc + page
+            } else {
+                println!("Failed to get page: {}", middle_page_index);
+                acc
+            }
+        } else {
+            acc
+        }
+    })
+}
+
+fn sort_update(mut update: Vec<i32>, rules: &[SortRule]) -> Vec<i32> {
+    // println!("Starting sort on {:?}", update);
+
+    loop {
+        let all_rules_passed = rules.iter().all(|rule| {
+            if !check_rule_passed(&update, rule) {
+                let rule_0_index = update
+                    .iter()
+                    .position(|x| *x == rule.0)
+                    .expect("Failed to find index of rule 0");
+                let rule_1_index = update
+                    .iter()
+                    .position(|x| *x == rule.1)
+                    .expect("Failed to find index of rule 0");
+                update.swap(rule_0_index, rule_1_index);
+                return false;
+            }
+
+            true
+        });
+
+        if all_rules_passed {
+            break;
+        }
+    }
+
+    update
+}
+
+fn check_update_passed(update: &[i32], rules: &[SortRule]) -> bool {
+    // Check all rules to make sure the update is good for each.
+    for rule in rules {
+        if !check_rule_passed(update, rule) {
+            return false;
+        }
+    }
+
+    // println!("Update passed: {:?}", update);
+    true
+}
+
+fn check_rule_passed(update: &[i32], rule: &SortRule) -> bool {
+    let index = update
+        .iter()
+        .position(|x| *x == rule.0)
+        .expect("Failed to find index of rule page 0");
+
+    let right_split = &update[index..];
+
+    // This would mean we failed a rule and thus the entire update.
+    if !right_split.contains(&rule.1) {
+        return false;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    #[test]
+    fn part_2_sample() {
+        let result = part_2(&parse(SAMPLE).unwrap());
+        assert_eq!(result, 123);
+    }
+}
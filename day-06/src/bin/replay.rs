@@ -0,0 +1,42 @@
+use std::{io, time::Duration};
+
+use aoc_common::input;
+use day_06::{
+    bad_value, guard_option, obstacle_option, parse, patrol::Patrol, replay, turn_option,
+};
+
+fn main() {
+    let args = input::from_args(
+        include_str!("./input.txt"),
+        &[],
+        &["--obstacle", "--guard", "--turn", "--delay", "--frames"],
+    );
+    let lab = input::or_exit(parse(&args.input));
+
+    let guard = input::or_exit(guard_option(&args, &lab));
+    let obstacle = input::or_exit(obstacle_option(&args));
+    let turn = input::or_exit(turn_option(&args));
+    let patrol = Patrol::for_guard(&lab, guard, obstacle).with_turn(turn);
+
+    if let Some(value) = args.option("--frames") {
+        let wanted: Result<Vec<usize>, _> =
+            value.split(',').map(|step| step.trim().parse()).collect();
+        let wanted =
+            input::or_exit(wanted.map_err(|_| {
+                bad_value("--frames", value, "a list of step numbers like `0,10,20`")
+            }));
+        println!("{}", replay::frames(patrol, &wanted));
+        return;
+    }
+
+    let delay = match args.option("--delay") {
+        Some(value) => input::or_exit(
+            value
+                .parse()
+                .map_err(|_| bad_value("--delay", value, "a number of milliseconds")),
+        ),
+        None => 100,
+    };
+    replay::play(patrol, Duration::from_millis(delay), &mut io::stdout())
+        .expect("Couldn't write to the terminal");
+}
@@ -0,0 +1,33 @@
+use std::io;
+
+use aoc_common::input;
+use day_06::{
+    bad_value, guard_option, obstacle_option, parse,
+    patrol::Patrol,
+    trace::{self, Format},
+    turn_option,
+};
+
+fn main() {
+    let args = input::from_args(
+        include_str!("./input.txt"),
+        &[],
+        &["--obstacle", "--guard", "--turn", "--format"],
+    );
+    let lab = input::or_exit(parse(&args.input));
+
+    let format = match args.option("--format") {
+        Some(value) => input::or_exit(
+            Format::from_name(value)
+                .ok_or_else(|| bad_value("--format", value, "`jsonl` or `csv`")),
+        ),
+        None => Format::JsonLines,
+    };
+
+    let guard = input::or_exit(guard_option(&args, &lab));
+    let obstacle = input::or_exit(obstacle_option(&args));
+    let turn = input::or_exit(turn_option(&args));
+    let patrol = Patrol::for_guard(&lab, guard, obstacle).with_turn(turn);
+    let steps = trace::trace(patrol);
+    trace::write(&steps, format, &mut io::stdout().lock()).expect("Couldn't write the trace");
+}
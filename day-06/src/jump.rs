@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+
+use aoc_common::{Direction, Grid, Position};
+
+use crate::{patrol::Turn, GuardStart, Tile};
+
+/// Where the obstacles are in each row and column, so the guard can go straight to the next one
+/// instead of walking there a step at a time.
+#[derive(Debug, Clone)]
+pub struct ObstacleIndex {
+    /// The `x` of every obstacle in each row, smallest first.
+    rows: Vec<Vec<i32>>,
+    /// The `y` of every obstacle in each column, smallest first.
+    columns: Vec<Vec<i32>>,
+}
+
+impl ObstacleIndex {
+    pub fn new(map: &Grid<Tile>) -> Self {
+        let mut rows = vec![vec![]; map.height()];
+        let mut columns = vec![vec![]; map.width()];
+        // `positions_of` goes row by row, so both lists come out sorted.
+        for position in map.positions_of(&Tile::Obstacle) {
+            rows[position.1 as usize].push(position.0);
+            columns[position.0 as usize].push(position.1);
+        }
+
+        ObstacleIndex { rows, columns }
+    }
+
+    /// The first obstacle from `position` looking `direction`, counting `extra` as an obstacle too.
+    /// Gives back `None` when there's nothing between the guard and the edge of the map.
+    pub fn next_obstacle(
+        &self,
+        position: Position,
+        direction: Direction,
+        extra: Option<Position>,
+    ) -> Option<Position> {
+        let Position(x, y) = position;
+        let ahead = |obstacle: Position| match direction {
+            Direction::Up => obstacle.0 == x && obstacle.1 < y,
+            Direction::Down => obstacle.0 == x && obstacle.1 > y,
+            Direction::Left => obstacle.1 == y && obstacle.0 < x,
+            Direction::Right => obstacle.1 == y && obstacle.0 > x,
+        };
+
+        let indexed = match direction {
+            Direction::Up => before(self.columns.get(x as usize)?, y).map(|y| Position(x, y)),
+            Direction::Down => after(self.columns.get(x as usize)?, y).map(|y| Position(x, y)),
+            Direction::Left => before(self.rows.get(y as usize)?, x).map(|x| Position(x, y)),
+            Direction::Right => after(self.rows.get(y as usize)?, x).map(|x| Position(x, y)),
+        };
+
+        match (indexed, extra.filter(|extra| ahead(*extra))) {
+            (Some(indexed), Some(extra)) => {
+                if indexed.manhattan_distance(position) < extra.manhattan_distance(position) {
+                    Some(indexed)
+                } else {
+                    Some(extra)
+                }
+            }
+            (indexed, extra) => indexed.or(extra),
+        }
+    }
+
+    /// Does the guard walking from `guard` and turning `turn` loop, with `extra` as one more obstacle?
+    /// Only the places the guard turns are remembered. Every loop has to turn, and the guard
+    /// turning in the same place facing the same way twice means it's going round again.
+    pub fn loops(&self, guard: GuardStart, turn: Turn, extra: Option<Position>) -> bool {
+        let mut position = guard.position;
+        let mut direction = guard.facing;
+        let mut turns = HashSet::new();
+
+        while let Some(obstacle) = self.next_obstacle(position, direction, extra) {
+            position = obstacle - direction.delta();
+            if !turns.insert((position, direction)) {
+                return true;
+            }
+            direction = turn.apply(direction);
+        }
+
+        false
+    }
+}
+
+/// The closest value in `sorted` below `value`.
+fn before(sorted: &[i32], value: i32) -> Option<i32> {
+    let index = sorted.partition_point(|other| *other < value);
+    index.checked_sub(1).map(|index| sorted[index])
+}
+
+/// The closest value in `sorted` above `value`.
+fn after(sorted: &[i32], value: i32) -> Option<i32> {
+    let index = sorted.partition_point(|other| *other <= value);
+    sorted.get(index).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part2::obstacle_makes_loop, Day06, SAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn next_obstacle() {
+        let lab = parse(SAMPLE).unwrap();
+        let index = ObstacleIndex::new(&lab.map);
+        assert_eq!(
+            index.next_obstacle(lab.guard().position, Direction::Up, None),
+            Some(Position(4, 0))
+        );
+        assert_eq!(
+            index.next_obstacle(Position(4, 1), Direction::Right, None),
+            Some(Position(9, 1))
+        );
+        assert_eq!(
+            index.next_obstacle(Position(4, 1), Direction::Left, None),
+            None
+        );
+        // The extra obstacle only counts when it's closer.
+        assert_eq!(
+            index.next_obstacle(lab.guard().position, Direction::Up, Some(Position(4, 3))),
+            Some(Position(4, 3))
+        );
+        assert_eq!(
+            index.next_obstacle(Position(4, 1), Direction::Up, Some(Position(4, 3))),
+            Some(Position(4, 0))
+        );
+    }
+
+    #[test]
+    fn sorted_neighbours() {
+        assert_eq!(before(&[1, 4, 7], 4), Some(1));
+        assert_eq!(before(&[1, 4, 7], 1), None);
+        assert_eq!(after(&[1, 4, 7], 4), Some(7));
+        assert_eq!(after(&[1, 4, 7], 7), None);
+    }
+
+    /// Try an obstacle in every open cell, not just the ones on the guard's route,
+    /// for every guard turning either way.
+    fn matches_step_by_step(input: &str) {
+        let lab = parse(input).unwrap();
+        let index = ObstacleIndex::new(&lab.map);
+        for guard in &lab.guards {
+            for turn in [Turn::Right, Turn::Left] {
+                for position in lab.map.positions_of(&Tile::Open) {
+                    if position == guard.position {
+                        continue;
+                    }
+                    assert_eq!(
+                        index.loops(*guard, turn, Some(position)),
+                        obstacle_makes_loop(&lab, *guard, turn, position),
+                        "{:?} turning {:?} with an obstacle at {:?}",
+                        guard,
+                        turn,
+                        position
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sample_matches_step_by_step() {
+        matches_step_by_step(SAMPLE);
+        matches_step_by_step(&SAMPLE.replace("#.........", "#>........"));
+    }
+
+    #[test]
+    #[ignore = "slow, run with `cargo test --release -p day-06 -- --ignored`"]
+    fn real_input_matches_step_by_step() {
+        matches_step_by_step(Day06::INPUT);
+    }
+}
@@ -0,0 +1,292 @@
+use std::fmt;
+
+use aoc_common::{
+    input::{normalise, DayArgs},
+    Direction, Grid, ParseError, ParseErrorKind, Position, Solution,
+};
+use patrol::Turn;
+
+pub mod jump;
+pub mod part1;
+pub mod part2;
+pub mod patrol;
+pub mod replay;
+pub mod trace;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Open,
+    Obstacle,
+}
+
+/// Where a guard is drawn on the map and which way it's facing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuardStart {
+    pub position: Position,
+    pub facing: Direction,
+}
+
+/// The lab as it is drawn in the puzzle input, before the guards start moving.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lab {
+    pub map: Grid<Tile>,
+    /// Every guard on the map, in reading order. There's always at least one.
+    pub guards: Vec<GuardStart>,
+}
+
+impl Lab {
+    /// The first guard, the one the puzzle is about.
+    pub fn guard(&self) -> GuardStart {
+        self.guards[0]
+    }
+}
+
+impl fmt::Display for GuardStart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` at {},{}",
+            guard_glyph(self.facing),
+            self.position.0,
+            self.position.1
+        )
+    }
+}
+
+/// How a guard facing `direction` is drawn.
+pub(crate) fn guard_glyph(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+    }
+}
+
+/// Which way a guard drawn as `c` is facing, if `c` is a guard at all.
+fn guard_facing(c: char) -> Option<Direction> {
+    match c {
+        '^' => Some(Direction::Up),
+        '>' => Some(Direction::Right),
+        'v' => Some(Direction::Down),
+        '<' => Some(Direction::Left),
+        _ => None,
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = include_str!("./bin/input.txt");
+
+    type Input = Lab;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Lab, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(lab: &Lab) -> i32 {
+        part1::part_1(lab)
+    }
+
+    fn part_2(lab: &Lab) -> Option<i32> {
+        Some(part2::part_2(lab))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Lab, ParseError> {
+    let input = &*normalise(input);
+    let chars = Grid::try_from(input).map_err(|error| error.with_day(Day06::DAY))?;
+    let map = chars.map(|c| match c {
+        '#' => Tile::Obstacle,
+        _ => Tile::Open,
+    });
+
+    let guards: Vec<GuardStart> = chars
+        .iter()
+        .filter_map(|(position, c)| guard_facing(*c).map(|facing| GuardStart { position, facing }))
+        .collect();
+    if guards.is_empty() {
+        return Err(
+            ParseError::at_end(input, ParseErrorKind::Missing("a guard, one of `^>v<`"))
+                .with_day(Day06::DAY),
+        );
+    }
+
+    Ok(Lab { map, guards })
+}
+
+/// `x,y`, the way the bins take a position on the command line.
+pub fn parse_position(value: &str) -> Option<Position> {
+    let (x, y) = value.split_once(',')?;
+    Some(Position(x.trim().parse().ok()?, y.trim().parse().ok()?))
+}
+
+/// What's wrong with the `value` a bin was given for `option`, for the bin to print.
+pub fn bad_value(option: &str, value: &str, expected: &str) -> String {
+    format!("`{} {}` should be {}", option, value, expected)
+}
+
+/// `--obstacle x,y`, for one more obstacle on the map.
+pub fn obstacle_option(args: &DayArgs) -> Result<Option<Position>, String> {
+    match args.option("--obstacle") {
+        Some(value) => parse_position(value)
+            .map(Some)
+            .ok_or_else(|| bad_value("--obstacle", value, "a position like `3,6`")),
+        None => Ok(None),
+    }
+}
+
+/// `--turn left` or `--turn right`, for which way the guards turn when they're blocked. Right if it isn't given.
+pub fn turn_option(args: &DayArgs) -> Result<Turn, String> {
+    match args.option("--turn") {
+        Some(value) => {
+            Turn::from_name(value).ok_or_else(|| bad_value("--turn", value, "`left` or `right`"))
+        }
+        None => Ok(Turn::Right),
+    }
+}
+
+/// `--guard <n>`, for which guard to follow, counting from 1 in reading order. The first one if it isn't given.
+pub fn guard_option(args: &DayArgs, lab: &Lab) -> Result<GuardStart, String> {
+    match args.option("--guard") {
+        Some(value) => value
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| lab.guards.get(n.checked_sub(1)?))
+            .copied()
+            .ok_or_else(|| {
+                bad_value(
+                    "--guard",
+                    value,
+                    &format!("between 1 and {}, the number of guards", lab.guards.len()),
+                )
+            }),
+        None => Ok(lab.guard()),
+    }
+}
+
+/// The sample from the puzzle, shared between both parts' tests.
+#[cfg(test)]
+pub(crate) const SAMPLE: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_without_guard() {
+        let error = parse("..#\n...").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Missing("a guard, one of `^>v<`")
+        );
+        assert_eq!(error.day, Some(6));
+    }
+
+    #[test]
+    fn parse_ragged_rows() {
+        let error = parse("..#\n.^").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (Some(6), 2, 3));
+    }
+
+    #[test]
+    fn parse_guards() {
+        let lab = parse("..>\n^..\n.<v").unwrap();
+        assert_eq!(
+            lab.guards,
+            vec![
+                GuardStart {
+                    position: Position(2, 0),
+                    facing: Direction::Right
+                },
+                GuardStart {
+                    position: Position(0, 1),
+                    facing: Direction::Up
+                },
+                GuardStart {
+                    position: Position(1, 2),
+                    facing: Direction::Left
+                },
+                GuardStart {
+                    position: Position(2, 2),
+                    facing: Direction::Down
+                },
+            ]
+        );
+        assert_eq!(lab.guard().position, Position(2, 0));
+        assert_eq!(lab.guards[3].to_string(), "`v` at 2,2");
+        assert!(lab.map.iter().all(|(_, tile)| *tile == Tile::Open));
+    }
+
+    #[test]
+    fn command_line_position() {
+        assert_eq!(parse_position("3,6"), Some(Position(3, 6)));
+        assert_eq!(parse_position("3, 6"), Some(Position(3, 6)));
+        assert_eq!(parse_position("3"), None);
+        assert_eq!(parse_position("3,x"), None);
+    }
+
+    #[test]
+    fn command_line_options() {
+        let lab = parse("..>\n^..").unwrap();
+        let args = |options: &[(&'static str, &str)]| DayArgs {
+            input: String::new(),
+            flags: vec![],
+            options: options
+                .iter()
+                .map(|(option, value)| (*option, value.to_string()))
+                .collect(),
+        };
+
+        let none = args(&[]);
+        assert_eq!(obstacle_option(&none), Ok(None));
+        assert_eq!(turn_option(&none), Ok(Turn::Right));
+        assert_eq!(guard_option(&none, &lab), Ok(lab.guards[0]));
+
+        let given = args(&[("--obstacle", "3,6"), ("--turn", "left"), ("--guard", "2")]);
+        assert_eq!(obstacle_option(&given), Ok(Some(Position(3, 6))));
+        assert_eq!(turn_option(&given), Ok(Turn::Left));
+        assert_eq!(guard_option(&given, &lab), Ok(lab.guards[1]));
+
+        let bad = args(&[("--obstacle", "3"), ("--turn", "back"), ("--guard", "3")]);
+        assert_eq!(
+            obstacle_option(&bad),
+            Err(String::from(
+                "`--obstacle 3` should be a position like `3,6`"
+            ))
+        );
+        assert_eq!(
+            turn_option(&bad),
+            Err(String::from("`--turn back` should be `left` or `right`"))
+        );
+        assert_eq!(
+            guard_option(&bad, &lab),
+            Err(String::from(
+                "`--guard 3` should be between 1 and 2, the number of guards"
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_trailing_newline_and_crlf() {
+        let expected = parse(SAMPLE).unwrap();
+
+        let with_newline = format!("{}\n", SAMPLE);
+        assert_eq!(parse(&with_newline).unwrap(), expected);
+
+        let with_crlf = format!("{}\r\n", SAMPLE.replace('\n', "\r\n"));
+        assert_eq!(parse(&with_crlf).unwrap(), expected);
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use aoc_common::Position;
+
+use crate::{
+    patrol::{Patrol, Turn},
+    GuardStart, Lab,
+};
+
+/// Every cell `guard` walks through, turning `turn` when it's blocked, before it leaves or starts going round in circles.
+pub fn visited(lab: &Lab, guard: GuardStart, turn: Turn) -> HashSet<Position> {
+    let mut patrol = Patrol::for_guard(lab, guard, None).with_turn(turn);
+    while patrol.step().is_some() {}
+
+    patrol.visited().clone()
+}
+
+/// How many cells at least one of the guards walks through.
+pub fn part_1_with(lab: &Lab, turn: Turn) -> i32 {
+    let mut cells = HashSet::new();
+    for guard in &lab.guards {
+        cells.extend(visited(lab, *guard, turn));
+    }
+
+    cells.len() as i32
+}
+
+pub fn part_1(lab: &Lab) -> i32 {
+    part_1_with(lab, Turn::Right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+    use aoc_common::Grid;
+
+    #[test]
+    fn sample() {
+        let result = part_1(&parse(SAMPLE).unwrap());
+        assert_eq!(result, 41);
+    }
+
+    #[test]
+    fn turning_left() {
+        let mirrored = parse(
+            &Grid::try_from(SAMPLE)
+                .unwrap()
+                .flip_horizontal()
+                .to_string(),
+        )
+        .unwrap();
+        assert_eq!(part_1_with(&mirrored, Turn::Left), 41);
+    }
+
+    #[test]
+    fn every_guard() {
+        // The second guard walks straight down off the map, from a cell the first one has been through
+        // to one it hasn't.
+        let lab = parse(&SAMPLE.replace("#.........", "#v........")).unwrap();
+        let each: Vec<usize> = lab
+            .guards
+            .iter()
+            .map(|guard| visited(&lab, *guard, Turn::Right).len())
+            .collect();
+        assert_eq!(each, vec![41, 2]);
+        assert_eq!(part_1_with(&lab, Turn::Right), 42);
+
+        // Facing right, it walks along the row the first guard came back through and then two cells further.
+        let lab = parse(&SAMPLE.replace("#.........", "#>........")).unwrap();
+        assert_eq!(visited(&lab, lab.guards[1], Turn::Right).len(), 9);
+        assert_eq!(part_1_with(&lab, Turn::Right), 43);
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::{BTreeSet, HashSet};
+
+use aoc_common::Position;
+use rayon::prelude::*;
+
+use crate::{
+    jump::ObstacleIndex,
+    part1::visited,
+    patrol::{Patrol, Turn},
+    GuardStart, Lab,
+};
+
+/// Everywhere worth putting an obstacle: the places the guard walks through without one, apart from where it starts.
+fn positions_to_test_obstacles(lab: &Lab, guard: GuardStart, turn: Turn) -> HashSet<Position> {
+    let mut positions = visited(lab, guard, turn);
+    positions.remove(&guard.position);
+    positions
+}
+
+/// Walk the guard a step at a time with an obstacle at `position` and see if it loops.
+pub(crate) fn obstacle_makes_loop(
+    lab: &Lab,
+    guard: GuardStart,
+    turn: Turn,
+    position: Position,
+) -> bool {
+    let mut patrol = Patrol::for_guard(lab, guard, Some(position)).with_turn(turn);
+    while patrol.step().is_some() {}
+
+    patrol.looped()
+}
+
+/// Every place a new obstacle would send `guard`, turning `turn` when blocked, round in a loop.
+pub fn loop_obstacles(lab: &Lab, guard: GuardStart, turn: Turn) -> BTreeSet<Position> {
+    let index = ObstacleIndex::new(&lab.map);
+
+    // Nothing is shared but the index, so every obstacle can be checked at the same time.
+    positions_to_test_obstacles(lab, guard, turn)
+        .into_par_iter()
+        .filter(|position| index.loops(guard, turn, Some(*position)))
+        .collect()
+}
+
+/// How many moves and turns each of the `obstacles` has the guard take to go round its loop once.  
+/// Gives back `None` for an obstacle the guard walks away from.
+pub fn loop_lengths(
+    lab: &Lab,
+    guard: GuardStart,
+    turn: Turn,
+    obstacles: &BTreeSet<Position>,
+) -> Vec<(Position, Option<usize>)> {
+    obstacles
+        .iter()
+        .map(|obstacle| {
+            let mut patrol = Patrol::for_guard(lab, guard, Some(*obstacle)).with_turn(turn);
+            while patrol.step().is_some() {}
+            (*obstacle, patrol.loop_length())
+        })
+        .collect()
+}
+
+/// How many places a new obstacle would send at least one of the guards round in a loop.
+pub fn part_2_with(lab: &Lab, turn: Turn) -> i32 {
+    let mut obstacles = BTreeSet::new();
+    for guard in &lab.guards {
+        obstacles.extend(loop_obstacles(lab, *guard, turn));
+    }
+
+    obstacles.len() as i32
+}
+
+pub fn part_2(lab: &Lab) -> i32 {
+    part_2_with(lab, Turn::Right)
+}
+
+/// `part_2` walking the guards a step at a time for one obstacle after another,
+/// to compare the parallel jumping version against.
+pub fn part_2_sequential(lab: &Lab) -> i32 {
+    let mut obstacles = HashSet::new();
+    for guard in &lab.guards {
+        obstacles.extend(
+            positions_to_test_obstacles(lab, *guard, Turn::Right)
+                .into_iter()
+                .filter(|position| obstacle_makes_loop(lab, *guard, Turn::Right, *position)),
+        );
+    }
+
+    obstacles.len() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, Day06, SAMPLE};
+    use aoc_common::{Grid, Solution};
+
+    #[test]
+    fn sample() {
+        let result = part_2(&parse(SAMPLE).unwrap());
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn sample_obstacles() {
+        let lab = parse(SAMPLE).unwrap();
+        let obstacles = loop_obstacles(&lab, lab.guard(), Turn::Right);
+        assert_eq!(
+            obstacles,
+            BTreeSet::from([
+                Position(3, 6),
+                Position(6, 7),
+                Position(7, 7),
+                Position(1, 8),
+                Position(3, 8),
+                Position(7, 9),
+            ])
+        );
+    }
+
+    #[test]
+    fn sample_loop_lengths() {
+        let lab = parse(SAMPLE).unwrap();
+        let obstacles = loop_obstacles(&lab, lab.guard(), Turn::Right);
+        let lengths = loop_lengths(&lab, lab.guard(), Turn::Right, &obstacles);
+        assert_eq!(
+            lengths,
+            vec![
+                (Position(1, 8), Some(20)),
+                (Position(3, 6), Some(22)),
+                (Position(3, 8), Some(42)),
+                (Position(6, 7), Some(16)),
+                (Position(7, 7), Some(16)),
+                (Position(7, 9), Some(18)),
+            ]
+        );
+        assert_eq!(
+            loop_lengths(
+                &lab,
+                lab.guard(),
+                Turn::Right,
+                &BTreeSet::from([Position(0, 0)])
+            ),
+            vec![(Position(0, 0), None)]
+        );
+    }
+
+    #[test]
+    fn turning_left() {
+        // Mirrored, with the guard turning left, the obstacles are mirrored too.
+        let mirrored = parse(
+            &Grid::try_from(SAMPLE)
+                .unwrap()
+                .flip_horizontal()
+                .to_string(),
+        )
+        .unwrap();
+        let lab = parse(SAMPLE).unwrap();
+        let obstacles: BTreeSet<Position> = loop_obstacles(&lab, lab.guard(), Turn::Right)
+            .iter()
+            .map(|position| Position(9 - position.0, position.1))
+            .collect();
+        assert_eq!(
+            loop_obstacles(&mirrored, mirrored.guard(), Turn::Left),
+            obstacles
+        );
+        assert_eq!(part_2_with(&mirrored, Turn::Left), 6);
+    }
+
+    #[test]
+    fn every_guard() {
+        let lab = parse(&SAMPLE.replace("#.........", "#>........")).unwrap();
+        let first = loop_obstacles(&lab, lab.guards[0], Turn::Right);
+        let second = loop_obstacles(&lab, lab.guards[1], Turn::Right);
+        assert_eq!(
+            part_2_with(&lab, Turn::Right) as usize,
+            first.union(&second).count()
+        );
+        assert_eq!(part_2(&lab), part_2_sequential(&lab));
+    }
+
+    #[test]
+    fn sequential_sample() {
+        let result = part_2_sequential(&parse(SAMPLE).unwrap());
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    #[ignore = "slow, run with `cargo test --release -p day-06 -- --ignored`"]
+    fn parallel_matches_sequential() {
+        let lab = parse(Day06::INPUT).unwrap();
+        assert_eq!(part_2(&lab), part_2_sequential(&lab));
+    }
+
+    fn loops(map: &str) -> bool {
+        let lab = parse(map).unwrap();
+        let mut patrol = Patrol::new(&lab, None);
+        while patrol.step().is_some() {}
+
+        patrol.looped()
+    }
+
+    #[test]
+    fn crossing_its_own_path_is_not_a_loop() {
+        // The guard comes back through where it started facing left instead of up, then walks off the map.
+        assert!(!loops(
+            ".#...
+....#
+.....
+.^...
+...#."
+        ));
+    }
+
+    #[test]
+    fn loop_after_crossing_its_own_path() {
+        // The guard crosses (2, 3) going up and then going left before it settles into a loop.
+        assert!(loops(
+            ".##..
+....#
+.....
+#....
+...#.
+#.^..
+....."
+        ));
+    }
+
+    #[test]
+    fn loop_through_the_start() {
+        assert!(loops(
+            ".#...
+....#
+.^...
+#....
+...#."
+        ));
+    }
+}
@@ -0,0 +1,287 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Direction, Grid, Position};
+
+use crate::{GuardStart, Lab, Tile};
+
+/// Which way a guard turns when something is in its way. The puzzle's guards always turn right.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Turn {
+    #[default]
+    Right,
+    Left,
+}
+
+impl Turn {
+    /// `left` or `right`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "right" => Some(Turn::Right),
+            "left" => Some(Turn::Left),
+            _ => None,
+        }
+    }
+
+    pub fn apply(self, direction: Direction) -> Direction {
+        match self {
+            Turn::Right => direction.turn_right(),
+            Turn::Left => direction.turn_left(),
+        }
+    }
+}
+
+/// What the guard did in one step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Move,
+    Turn,
+    /// Moved off the edge of the map, which is the end of the patrol.
+    Exit,
+    /// Came back to somewhere it has already been facing the same way, so it'll go round forever.
+    Loop,
+}
+
+impl Event {
+    pub fn name(self) -> &'static str {
+        match self {
+            Event::Move => "move",
+            Event::Turn => "turn",
+            Event::Exit => "exit",
+            Event::Loop => "loop",
+        }
+    }
+}
+
+/// The guard walking through the lab one step at a time. This is the one place the rules for moving
+/// and turning live, apart from `ObstacleIndex` jumping ahead for speed.
+#[derive(Debug, Clone)]
+pub struct Patrol<'a> {
+    map: &'a Grid<Tile>,
+    obstacle: Option<Position>,
+    position: Position,
+    direction: Direction,
+    turn: Turn,
+    /// Every cell the guard has stood on.
+    visited: HashSet<Position>,
+    /// The step each `(position, direction)` the guard has been in was first reached at,
+    /// to spot it going round again and say how far round it is.
+    seen: HashMap<(Position, Direction), usize>,
+    steps: usize,
+    loop_length: Option<usize>,
+}
+
+impl<'a> Patrol<'a> {
+    /// Start the lab's first guard, with `obstacle` as one more obstacle on the map.
+    pub fn new(lab: &'a Lab, obstacle: Option<Position>) -> Self {
+        Patrol::for_guard(lab, lab.guard(), obstacle)
+    }
+
+    /// Start any one of the lab's guards, or one placed anywhere else on the map.
+    pub fn for_guard(lab: &'a Lab, guard: GuardStart, obstacle: Option<Position>) -> Self {
+        Patrol {
+            map: &lab.map,
+            obstacle,
+            position: guard.position,
+            direction: guard.facing,
+            turn: Turn::default(),
+            visited: HashSet::from([guard.position]),
+            seen: HashMap::from([((guard.position, guard.facing), 0)]),
+            steps: 0,
+            loop_length: None,
+        }
+    }
+
+    /// A patrol for every guard in the lab. They don't get in each other's way, so each one
+    /// walks exactly as it would if it were on its own.
+    pub fn every_guard(lab: &'a Lab, obstacle: Option<Position>) -> Vec<Self> {
+        lab.guards
+            .iter()
+            .map(|guard| Patrol::for_guard(lab, *guard, obstacle))
+            .collect()
+    }
+
+    /// Have the guard turn the other way when it's blocked.
+    pub fn with_turn(mut self, turn: Turn) -> Self {
+        self.turn = turn;
+        self
+    }
+
+    pub fn map(&self) -> &'a Grid<Tile> {
+        self.map
+    }
+
+    pub fn obstacle(&self) -> Option<Position> {
+        self.obstacle
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn visited(&self) -> &HashSet<Position> {
+        &self.visited
+    }
+
+    /// How many moves and turns the guard has made.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn on_map(&self) -> bool {
+        self.map.in_bounds(self.position)
+    }
+
+    /// Has the guard come back to somewhere it has already been, facing the same way?
+    pub fn looped(&self) -> bool {
+        self.loop_length.is_some()
+    }
+
+    /// Once the guard has looped, how many moves and turns it takes to go round once.
+    pub fn loop_length(&self) -> Option<usize> {
+        self.loop_length
+    }
+
+    /// Is there nothing left to watch, because the guard has walked off the map or is going round in circles?
+    pub fn finished(&self) -> bool {
+        !self.on_map() || self.looped()
+    }
+
+    fn blocked(&self, position: Position) -> bool {
+        self.obstacle == Some(position) || self.map.get(position) == Some(&Tile::Obstacle)
+    }
+
+    /// Move the guard forward, or turn it if something is in the way.
+    /// Gives back `None`, without doing anything, once the patrol has finished.
+    pub fn step(&mut self) -> Option<Event> {
+        if self.finished() {
+            return None;
+        }
+
+        let ahead = self.position.step(self.direction);
+        let mut event = if self.blocked(ahead) {
+            self.direction = self.turn.apply(self.direction);
+            Event::Turn
+        } else {
+            self.position = ahead;
+            Event::Move
+        };
+        self.steps += 1;
+
+        if !self.on_map() {
+            event = Event::Exit;
+        } else if let Some(first) = self.seen.get(&(self.position, self.direction)) {
+            self.loop_length = Some(self.steps - first);
+            event = Event::Loop;
+        } else {
+            self.seen
+                .insert((self.position, self.direction), self.steps);
+            self.visited.insert(self.position);
+        }
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    /// Run the patrol to the end and give back how many cells the guard saw.
+    fn visited(mut patrol: Patrol) -> usize {
+        while patrol.step().is_some() {}
+        patrol.visited().len()
+    }
+
+    #[test]
+    fn walks_off_the_sample() {
+        let lab = parse(SAMPLE).unwrap();
+        let mut patrol = Patrol::new(&lab, None);
+        let mut events = vec![];
+        while let Some(event) = patrol.step() {
+            events.push(event);
+        }
+
+        assert!(!patrol.on_map());
+        assert!(!patrol.looped());
+        assert_eq!(patrol.visited().len(), 41);
+        assert_eq!(patrol.step(), None);
+        // Five steps up to the first obstacle, where it turns, and off the bottom at the end.
+        assert_eq!(
+            events[..6],
+            [
+                Event::Move,
+                Event::Move,
+                Event::Move,
+                Event::Move,
+                Event::Move,
+                Event::Turn
+            ]
+        );
+        assert_eq!(events.last(), Some(&Event::Exit));
+    }
+
+    #[test]
+    fn loops_with_an_obstacle() {
+        let lab = parse(SAMPLE).unwrap();
+        let mut patrol = Patrol::new(&lab, Some(Position(3, 6)));
+        let mut last = None;
+        while let Some(event) = patrol.step() {
+            last = Some(event);
+        }
+
+        assert_eq!(last, Some(Event::Loop));
+        assert!(patrol.on_map());
+        assert!(patrol.looped());
+        // It's back where it started, so the whole patrol is the loop.
+        assert_eq!(patrol.loop_length(), Some(22));
+    }
+
+    #[test]
+    fn turning_left_in_a_mirror() {
+        let lab = parse(SAMPLE).unwrap();
+        let mirrored = Grid::try_from(SAMPLE)
+            .unwrap()
+            .flip_horizontal()
+            .to_string();
+        let mirrored = parse(&mirrored).unwrap();
+
+        // Turning right in the mirror image sends the guard somewhere else entirely.
+        assert_eq!(visited(Patrol::new(&mirrored, None)), 10);
+        assert_eq!(
+            visited(Patrol::new(&mirrored, None).with_turn(Turn::Left)),
+            visited(Patrol::new(&lab, None))
+        );
+    }
+
+    #[test]
+    fn starting_facing_any_way() {
+        // Turned a quarter clockwise, the map's guard should start off facing right.
+        let turned = Grid::try_from(SAMPLE)
+            .unwrap()
+            .rotate_clockwise()
+            .to_string()
+            .replace('^', ">");
+        let turned = parse(&turned).unwrap();
+        assert_eq!(turned.guard().facing, Direction::Right);
+        assert_eq!(visited(Patrol::new(&turned, None)), 41);
+    }
+
+    #[test]
+    fn guards_walk_on_their_own() {
+        // The second guard walks straight down off the map, through the first one's path.
+        let lab = parse(&SAMPLE.replace("#.........", "#v........")).unwrap();
+        let mut patrols = Patrol::every_guard(&lab, None);
+        assert_eq!(patrols.len(), 2);
+        for patrol in &mut patrols {
+            while patrol.step().is_some() {}
+        }
+
+        assert_eq!(patrols[0].visited().len(), 41);
+        assert_eq!(patrols[1].visited().len(), 2);
+        assert!(patrols.iter().all(|patrol| !patrol.on_map()));
+    }
+}
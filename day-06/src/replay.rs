@@ -0,0 +1,205 @@
+use std::{collections::BTreeSet, io, thread, time::Duration};
+
+use aoc_common::Position;
+
+use crate::{guard_glyph, patrol::Patrol, Lab, Tile};
+
+/// Moves the cursor to the top left and clears the terminal, so each frame draws over the last.
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+/// The lab drawn the way the puzzle does: `#` for the obstacles, `X` wherever the guard has been and
+/// the guard itself as `^>v<`. The extra obstacle, if there is one, is an `O`.
+pub fn frame(patrol: &Patrol) -> String {
+    let mut grid = patrol.map().map(|tile| match tile {
+        Tile::Open => '.',
+        Tile::Obstacle => '#',
+    });
+    for position in patrol.visited() {
+        grid[*position] = 'X';
+    }
+    if let Some(obstacle) = patrol.obstacle() {
+        grid.set(obstacle, 'O');
+    }
+    grid.set(patrol.position(), guard_glyph(patrol.direction()));
+
+    grid.to_string()
+}
+
+/// The lab before the guards move, with an `O` in each of the `obstacles`.
+pub fn obstacle_map(lab: &Lab, obstacles: &BTreeSet<Position>) -> String {
+    let mut grid = lab.map.map(|tile| match tile {
+        Tile::Open => '.',
+        Tile::Obstacle => '#',
+    });
+    for guard in &lab.guards {
+        grid[guard.position] = guard_glyph(guard.facing);
+    }
+    for obstacle in obstacles {
+        grid.set(*obstacle, 'O');
+    }
+
+    grid.to_string()
+}
+
+/// What's happening in the frame, shown above it.
+fn caption(patrol: &Patrol) -> String {
+    if !patrol.on_map() {
+        format!("Step {}: the guard has left the lab", patrol.steps())
+    } else if patrol.looped() {
+        format!(
+            "Step {}: the guard is going round in circles",
+            patrol.steps()
+        )
+    } else {
+        format!("Step {}", patrol.steps())
+    }
+}
+
+/// The frames for the `wanted` steps of `patrol`, each under its caption, without any of the terminal animation.
+/// A step past the end of the patrol gives the last frame, so a big number is a way of asking for how it finishes.
+pub fn frames(mut patrol: Patrol, wanted: &[usize]) -> String {
+    let mut frames = vec![];
+
+    loop {
+        let last = patrol.finished();
+        if wanted
+            .iter()
+            .any(|step| *step == patrol.steps() || (last && *step > patrol.steps()))
+        {
+            frames.push(format!("{}\n{}", caption(&patrol), frame(&patrol)));
+        }
+        if patrol.step().is_none() {
+            break;
+        }
+    }
+
+    frames.join("\n\n")
+}
+
+/// Animate `patrol` in the terminal, waiting `delay` between each step.
+pub fn play(mut patrol: Patrol, delay: Duration, out: &mut impl io::Write) -> io::Result<()> {
+    loop {
+        write!(out, "{}{}\n{}\n", CLEAR, caption(&patrol), frame(&patrol))?;
+        out.flush()?;
+        if patrol.step().is_none() {
+            return Ok(());
+        }
+        thread::sleep(delay);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, patrol::Turn, SAMPLE};
+
+    #[test]
+    fn first_frame_is_the_map() {
+        let lab = parse(SAMPLE).unwrap();
+        assert_eq!(
+            frames(Patrol::new(&lab, None), &[0]),
+            format!("Step 0\n{}", SAMPLE)
+        );
+    }
+
+    #[test]
+    fn guard_turns_at_the_first_obstacle() {
+        let lab = parse(SAMPLE).unwrap();
+        assert_eq!(
+            frames(Patrol::new(&lab, None), &[5, 6]),
+            "Step 5
+....#.....
+....^....#
+....X.....
+..#.X.....
+....X..#..
+....X.....
+.#..X.....
+........#.
+#.........
+......#...
+
+Step 6
+....#.....
+....>....#
+....X.....
+..#.X.....
+....X..#..
+....X.....
+.#..X.....
+........#.
+#.........
+......#..."
+        );
+    }
+
+    #[test]
+    fn last_frame_matches_the_puzzle() {
+        let lab = parse(SAMPLE).unwrap();
+        assert_eq!(
+            frames(Patrol::new(&lab, None), &[usize::MAX]),
+            "Step 55: the guard has left the lab
+....#.....
+....XXXXX#
+....X...X.
+..#.X...X.
+..XXXXX#X.
+..X.X.X.X.
+.#XXXXXXX.
+.XXXXXXX#.
+#XXXXXXX..
+......#X.."
+        );
+    }
+
+    #[test]
+    fn candidate_obstacle() {
+        let lab = parse(SAMPLE).unwrap();
+        // The first of the puzzle's loop obstacles sends the guard back to where it started.
+        assert_eq!(
+            frames(Patrol::new(&lab, Some(Position(3, 6))), &[usize::MAX]),
+            "Step 22: the guard is going round in circles
+....#.....
+....XXXXX#
+....X...X.
+..#.X...X.
+....X..#X.
+....X...X.
+.#.O^XXXX.
+........#.
+#.........
+......#..."
+        );
+    }
+
+    #[test]
+    fn puzzle_loop_obstacles() {
+        let lab = parse(SAMPLE).unwrap();
+        assert_eq!(
+            obstacle_map(
+                &lab,
+                &crate::part2::loop_obstacles(&lab, lab.guard(), Turn::Right)
+            ),
+            "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#.O^.....
+......OO#.
+#O.O......
+......#O.."
+        );
+    }
+
+    #[test]
+    fn play_draws_every_step() {
+        let lab = parse(SAMPLE).unwrap();
+        let mut out = vec![];
+        play(Patrol::new(&lab, None), Duration::ZERO, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches(CLEAR).count(), 56);
+        assert!(out.ends_with("......#X..\n"));
+    }
+}
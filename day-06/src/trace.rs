@@ -0,0 +1,155 @@
+use std::io;
+
+use aoc_common::{Direction, Position};
+
+use crate::patrol::{Event, Patrol};
+
+/// Where the guard was and which way it was facing after one step of the patrol, and what the step was.
+/// An `Exit` step leaves the guard just off the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub step: usize,
+    pub position: Position,
+    pub direction: Direction,
+    pub event: Event,
+}
+
+/// How to write a trace out, for loading into other tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One JSON object per line.
+    JsonLines,
+    /// A header line, then one row per step.
+    Csv,
+}
+
+impl Format {
+    /// `jsonl` or `csv`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "jsonl" => Some(Format::JsonLines),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+fn direction_name(direction: Direction) -> &'static str {
+    match direction {
+        Direction::Up => "up",
+        Direction::Down => "down",
+        Direction::Left => "left",
+        Direction::Right => "right",
+    }
+}
+
+/// Every step of `patrol`, up to the guard leaving or looping.
+pub fn trace(mut patrol: Patrol) -> Vec<Step> {
+    let mut steps = vec![];
+
+    while let Some(event) = patrol.step() {
+        steps.push(Step {
+            step: patrol.steps(),
+            position: patrol.position(),
+            direction: patrol.direction(),
+            event,
+        });
+    }
+
+    steps
+}
+
+/// Write the `steps` out in `format`, one line each.
+pub fn write(steps: &[Step], format: Format, out: &mut impl io::Write) -> io::Result<()> {
+    if format == Format::Csv {
+        writeln!(out, "step,x,y,direction,event")?;
+    }
+
+    for step in steps {
+        let Position(x, y) = step.position;
+        let direction = direction_name(step.direction);
+        let event = step.event.name();
+        match format {
+            Format::JsonLines => writeln!(
+                out,
+                r#"{{"step":{},"x":{},"y":{},"direction":"{}","event":"{}"}}"#,
+                step.step, x, y, direction, event
+            )?,
+            Format::Csv => writeln!(out, "{},{},{},{},{}", step.step, x, y, direction, event)?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    fn written(steps: &[Step], format: Format) -> String {
+        let mut out = vec![];
+        write(steps, format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn plain_patrol() {
+        let steps = trace(Patrol::new(&parse(SAMPLE).unwrap(), None));
+        assert_eq!(steps.len(), 55);
+        assert_eq!(
+            steps[5],
+            Step {
+                step: 6,
+                position: Position(4, 1),
+                direction: Direction::Right,
+                event: Event::Turn,
+            }
+        );
+        assert_eq!(
+            steps.last(),
+            Some(&Step {
+                step: 55,
+                position: Position(7, 10),
+                direction: Direction::Down,
+                event: Event::Exit,
+            })
+        );
+    }
+
+    #[test]
+    fn looping_patrol() {
+        let steps = trace(Patrol::new(&parse(SAMPLE).unwrap(), Some(Position(3, 6))));
+        assert_eq!(
+            steps.last(),
+            Some(&Step {
+                step: 22,
+                position: Position(4, 6),
+                direction: Direction::Up,
+                event: Event::Loop,
+            })
+        );
+    }
+
+    #[test]
+    fn write_formats() {
+        let steps = &trace(Patrol::new(&parse(SAMPLE).unwrap(), None))[4..6];
+        assert_eq!(
+            written(steps, Format::JsonLines),
+            r#"{"step":5,"x":4,"y":1,"direction":"up","event":"move"}
+{"step":6,"x":4,"y":1,"direction":"right","event":"turn"}
+"#
+        );
+        assert_eq!(
+            written(steps, Format::Csv),
+            "step,x,y,direction,event\n5,4,1,up,move\n6,4,1,right,turn\n"
+        );
+    }
+
+    #[test]
+    fn format_names() {
+        assert_eq!(Format::from_name("jsonl"), Some(Format::JsonLines));
+        assert_eq!(Format::from_name("csv"), Some(Format::Csv));
+        assert_eq!(Format::from_name("json"), None);
+    }
+}
@@ -0,0 +1,40 @@
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_07::{
+    solver::Operator::{self, Add, Concat, Multiply},
+    Day07, Equation,
+};
+
+/// How many equations each solver can solve, so the benchmark can't be optimised down to nothing.
+fn count(equations: &[Equation], solve: impl Fn(&Equation) -> Option<Vec<Operator>>) -> usize {
+    equations
+        .iter()
+        .filter(|equation| solve(black_box(equation)).is_some())
+        .count()
+}
+
+/// The forward and reverse solvers against each other on the real input, for both parts' operators.
+fn solvers(c: &mut Criterion) {
+    let equations = Day07::parse(Day07::INPUT).expect("the real input parses");
+
+    for (part, allowed) in [
+        ("part 1", &[Add, Multiply][..]),
+        ("part 2", &[Add, Multiply, Concat]),
+    ] {
+        let mut group = c.benchmark_group(format!("day-07/{}", part));
+        group.bench_function("forward", |b| {
+            b.iter(|| count(&equations, |equation| equation.solve(allowed)))
+        });
+        group.bench_function("reverse", |b| {
+            b.iter(|| count(&equations, |equation| equation.solve_reverse(allowed)))
+        });
+        group.finish();
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = solvers
+}
+criterion_main!(benches);
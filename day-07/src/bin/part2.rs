@@ -0,0 +1,16 @@
+use aoc_common::input;
+use day_07::{
+    explain, parse,
+    part2::{part_2, OPERATORS},
+};
+
+fn main() {
+    let args = input::from_args(include_str!("./input.txt"), &["--expressions"], &[]);
+    let equations = input::or_exit(parse(&args.input));
+    if args.flag("--expressions") {
+        println!("{}", explain(&equations, &OPERATORS));
+    }
+
+    let output = part_2(&equations);
+    dbg!(output);
+}
@@ -0,0 +1,151 @@
+use std::fmt;
+
+use aoc_common::{input::normalise, ParseError, ParseErrorKind, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, space1},
+    combinator::all_consuming,
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+pub mod solver;
+
+use solver::Operator;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equation {
+    pub test_value: u64,
+    pub numbers: Vec<u64>,
+}
+
+impl fmt::Display for Equation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.test_value)?;
+        for number in &self.numbers {
+            write!(f, " {}", number)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("./bin/input.txt");
+
+    type Input = Vec<Equation>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(equations: &Vec<Equation>) -> u64 {
+        part1::part_1(equations)
+    }
+
+    fn part_2(equations: &Vec<Equation>) -> Option<u64> {
+        Some(part2::part_2(equations))
+    }
+}
+
+fn equation(input: &str) -> IResult<&str, Equation> {
+    let (input, (test_value, numbers)) = separated_pair(
+        complete::u64,
+        tag(": "),
+        separated_list1(space1, complete::u64),
+    )(input)?;
+
+    Ok((
+        input,
+        Equation {
+            test_value,
+            numbers,
+        },
+    ))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+    let input = &*normalise(input);
+    input
+        .split('\n')
+        .map(|line| {
+            let (_, equation) = all_consuming(equation)(line).map_err(|error| {
+                ParseError::from_nom(
+                    input,
+                    error,
+                    ParseErrorKind::Expected("an equation like `190: 10 19`"),
+                )
+                .with_day(Day07::DAY)
+            })?;
+
+            Ok(equation)
+        })
+        .collect()
+}
+
+/// Every way to solve each equation, one line per expression, for checking the solver by eye.
+pub fn explain(equations: &[Equation], allowed: &[Operator]) -> String {
+    let mut explained = vec![];
+    for equation in equations {
+        explained.push(equation.to_string());
+        let solutions = equation.solutions(allowed);
+        if solutions.is_empty() {
+            explained.push(String::from("  no solution"));
+        }
+        for operators in solutions {
+            explained.push(format!("  {}", equation.expression(&operators)));
+        }
+    }
+    explained.join("\n")
+}
+
+/// The sample from the puzzle, shared between the tests.
+#[cfg(test)]
+pub(crate) const SAMPLE: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bad_equation() {
+        let error = parse("190: 10 19\n3267: 81 x 27").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (Some(7), 2, 10));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn explain_each_line() {
+        let equations = parse("190: 10 19\n83: 17 5").unwrap();
+        assert_eq!(
+            explain(&equations, &[Operator::Add, Operator::Multiply]),
+            "190: 10 19\n  10 * 19\n83: 17 5\n  no solution"
+        );
+    }
+
+    #[test]
+    fn parse_trailing_newline_and_crlf() {
+        let sample = "190: 10 19\n3267: 81 40 27";
+        let expected = parse(sample).unwrap();
+
+        let with_newline = format!("{}\n", sample);
+        assert_eq!(parse(&with_newline).unwrap(), expected);
+
+        let with_crlf = format!("{}\r\n", sample.replace('\n', "\r\n"));
+        assert_eq!(parse(&with_crlf).unwrap(), expected);
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{solver::Operator, Equation};
+
+/// The operators part 1 can put between the numbers.
+pub const OPERATORS: [Operator; 2] = [Operator::Add, Operator::Multiply];
+
+pub fn part_1(equations: &[Equation]) -> u64 {
+    equations
+        .iter()
+        .filter(|equation| equation.solve(&OPERATORS).is_some())
+        .map(|equation| equation.test_value)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    #[test]
+    fn sample() {
+        let result = part_1(&parse(SAMPLE).unwrap());
+        assert_eq!(result, 3749);
+    }
+}
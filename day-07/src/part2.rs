@@ -0,0 +1,24 @@
+use crate::{solver::Operator, Equation};
+
+/// The operators part 2 can put between the numbers.
+pub const OPERATORS: [Operator; 3] = [Operator::Add, Operator::Multiply, Operator::Concat];
+
+pub fn part_2(equations: &[Equation]) -> u64 {
+    equations
+        .iter()
+        .filter(|equation| equation.solve(&OPERATORS).is_some())
+        .map(|equation| equation.test_value)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    #[test]
+    fn sample() {
+        let result = part_2(&parse(SAMPLE).unwrap());
+        assert_eq!(result, 11387);
+    }
+}
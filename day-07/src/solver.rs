@@ -0,0 +1,495 @@
+use std::fmt;
+
+use crate::Equation;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Multiply,
+    /// `||`, joining the digits of the two numbers, so `12 || 345` is `12345`.
+    Concat,
+}
+
+impl Operator {
+    /// Apply the operator, or `None` if the result doesn't fit in a `u64`.
+    fn eval(self, a: u64, b: u64) -> Option<u64> {
+        match self {
+            Operator::Add => a.checked_add(b),
+            Operator::Multiply => a.checked_mul(b),
+            Operator::Concat => a.checked_mul(concat_shift(b)?)?.checked_add(b),
+        }
+    }
+
+    /// The other way round to `eval`: the `a` that makes `a op b` equal `result`, if there is one.  
+    /// Multiplying by zero can't be undone, as any `a` would do. See `absorbs` for that case.
+    fn undo(self, result: u64, b: u64) -> Option<u64> {
+        match self {
+            Operator::Add => result.checked_sub(b),
+            Operator::Multiply => (b != 0 && result.is_multiple_of(b)).then(|| result / b),
+            Operator::Concat => {
+                let shift = concat_shift(b)?;
+                (result % shift == b).then(|| result / shift)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operator::Add => f.pad("+"),
+            Operator::Multiply => f.pad("*"),
+            Operator::Concat => f.pad("||"),
+        }
+    }
+}
+
+/// Is `a op b` equal to `result` whatever `a` is? That's multiplying by zero to get zero,
+/// which `undo` can't give a single answer for.
+fn absorbs(operator: Operator, result: u64, b: u64) -> bool {
+    operator == Operator::Multiply && b == 0 && result == 0
+}
+
+/// Find any operators for `numbers` that can be worked out left to right without overflowing,
+/// for when whatever they come to is going to be multiplied by zero.
+fn fits(acc: u64, numbers: &[u64], allowed: &[Operator], used: &mut Vec<Operator>) -> bool {
+    let Some((&next, rest)) = numbers.split_first() else {
+        return true;
+    };
+
+    for &operator in allowed {
+        let Some(value) = operator.eval(acc, next) else {
+            continue;
+        };
+
+        used.push(operator);
+        if fits(value, rest, allowed, used) {
+            return true;
+        }
+        used.pop();
+    }
+
+    false
+}
+
+/// Like `fits`, but carrying on so `found` ends up with every sequence that doesn't overflow.
+fn all_fitting(
+    acc: u64,
+    numbers: &[u64],
+    allowed: &[Operator],
+    used: &mut Vec<Operator>,
+    found: &mut Vec<Vec<Operator>>,
+) {
+    let Some((&next, rest)) = numbers.split_first() else {
+        found.push(used.clone());
+        return;
+    };
+
+    for &operator in allowed {
+        if let Some(value) = operator.eval(acc, next) {
+            used.push(operator);
+            all_fitting(value, rest, allowed, used, found);
+            used.pop();
+        }
+    }
+}
+
+/// What to multiply by to make room for the digits of `b` when concatenating.
+fn concat_shift(b: u64) -> Option<u64> {
+    let mut shift: u64 = 10;
+    while shift <= b {
+        shift = shift.checked_mul(10)?;
+    }
+    Some(shift)
+}
+
+/// Try each of the `allowed` operators between `acc` and the next number, carrying the result on to the rest.
+/// `used` holds the operators used so far, and is left holding the full sequence when this returns true.  
+/// A branch that overflows can't reach the test value, so it is dropped there and then.
+fn recursive_eval(
+    acc: u64,
+    numbers: &[u64],
+    test_value: u64,
+    allowed: &[Operator],
+    used: &mut Vec<Operator>,
+) -> bool {
+    let Some((&next, rest)) = numbers.split_first() else {
+        return acc == test_value;
+    };
+
+    for &operator in allowed {
+        let Some(value) = operator.eval(acc, next) else {
+            continue;
+        };
+
+        used.push(operator);
+        if recursive_eval(value, rest, test_value, allowed, used) {
+            return true;
+        }
+        used.pop();
+    }
+
+    false
+}
+
+/// Work back from `target` by undoing each of the `allowed` operators on the last number.
+/// Most operators can't be undone for most targets, so branches die off far sooner than going forwards.  
+/// `used` is filled in last operator first.
+fn reverse_eval(
+    target: u64,
+    numbers: &[u64],
+    allowed: &[Operator],
+    used: &mut Vec<Operator>,
+) -> bool {
+    let Some((&last, rest)) = numbers.split_last() else {
+        return false;
+    };
+    if rest.is_empty() {
+        return last == target;
+    }
+
+    for &operator in allowed {
+        if absorbs(operator, target, last) {
+            let mut prefix = vec![];
+            if fits(rest[0], &rest[1..], allowed, &mut prefix) {
+                used.push(operator);
+                used.extend(prefix.iter().rev());
+                return true;
+            }
+            continue;
+        }
+
+        let Some(remaining) = operator.undo(target, last) else {
+            continue;
+        };
+
+        used.push(operator);
+        if reverse_eval(remaining, rest, allowed, used) {
+            return true;
+        }
+        used.pop();
+    }
+
+    false
+}
+
+/// Like `reverse_eval`, but carrying on after a match so `found` ends up with every sequence that works.
+fn reverse_all(
+    target: u64,
+    numbers: &[u64],
+    allowed: &[Operator],
+    used: &mut Vec<Operator>,
+    found: &mut Vec<Vec<Operator>>,
+) {
+    let Some((&last, rest)) = numbers.split_last() else {
+        return;
+    };
+    if rest.is_empty() {
+        if last == target {
+            found.push(used.iter().rev().copied().collect());
+        }
+        return;
+    }
+
+    for &operator in allowed {
+        if absorbs(operator, target, last) {
+            let mut prefixes = vec![];
+            all_fitting(rest[0], &rest[1..], allowed, &mut vec![], &mut prefixes);
+            for mut prefix in prefixes {
+                prefix.push(operator);
+                prefix.extend(used.iter().rev());
+                found.push(prefix);
+            }
+        } else if let Some(remaining) = operator.undo(target, last) {
+            used.push(operator);
+            reverse_all(remaining, rest, allowed, used, found);
+            used.pop();
+        }
+    }
+}
+
+impl Equation {
+    /// The operators, picked from `allowed`, that make the numbers equal the test value evaluated strictly left to right.  
+    /// Gives back `None` when no combination of operators works.
+    pub fn solve(&self, allowed: &[Operator]) -> Option<Vec<Operator>> {
+        let (&first, rest) = self.numbers.split_first()?;
+        let mut used = vec![];
+        recursive_eval(first, rest, self.test_value, allowed, &mut used).then_some(used)
+    }
+
+    /// The same as `solve`, but searching backwards from the test value.
+    /// It can find a different sequence of operators when there is more than one that works.
+    pub fn solve_reverse(&self, allowed: &[Operator]) -> Option<Vec<Operator>> {
+        let mut used = vec![];
+        if !reverse_eval(self.test_value, &self.numbers, allowed, &mut used) {
+            return None;
+        }
+
+        used.reverse();
+        Some(used)
+    }
+
+    /// Every sequence of operators, picked from `allowed`, that makes the numbers equal the test value.
+    pub fn solutions(&self, allowed: &[Operator]) -> Vec<Vec<Operator>> {
+        let mut found = vec![];
+        reverse_all(
+            self.test_value,
+            &self.numbers,
+            allowed,
+            &mut vec![],
+            &mut found,
+        );
+        found
+    }
+
+    /// The numbers with `operators` between them, like `81 + 40 * 27`.
+    pub fn expression(&self, operators: &[Operator]) -> String {
+        let mut expression = String::new();
+        for (i, number) in self.numbers.iter().enumerate() {
+            if i > 0 {
+                let operator = operators
+                    .get(i - 1)
+                    .map_or(String::from("?"), Operator::to_string);
+                expression.push_str(&format!(" {} ", operator));
+            }
+            expression.push_str(&number.to_string());
+        }
+        expression
+    }
+
+    /// Put `operators` between the numbers and work it out left to right.  
+    /// Gives back `None` if it overflows or there isn't one operator for each gap.
+    pub fn evaluate(&self, operators: &[Operator]) -> Option<u64> {
+        let (&first, rest) = self.numbers.split_first()?;
+        if operators.len() != rest.len() {
+            return None;
+        }
+
+        rest.iter()
+            .zip(operators)
+            .try_fold(first, |acc, (&number, operator)| operator.eval(acc, number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, Day07, SAMPLE};
+    use aoc_common::Solution;
+
+    fn solve(line: &str) -> Option<Vec<Operator>> {
+        parse(line).unwrap()[0].solve(&[Operator::Add, Operator::Multiply])
+    }
+
+    #[test]
+    fn solve_left_to_right() {
+        use Operator::*;
+
+        assert_eq!(solve("190: 10 19"), Some(vec![Multiply]));
+        assert_eq!(solve("3267: 81 40 27"), Some(vec![Add, Multiply]));
+        // 11 + 6 * 16 + 20 is 292 left to right, but wouldn't be with the usual precedence.
+        assert_eq!(solve("292: 11 6 16 20"), Some(vec![Add, Multiply, Add]));
+        assert_eq!(solve("7: 7"), Some(vec![]));
+    }
+
+    #[test]
+    fn unsolvable() {
+        assert_eq!(solve("83: 17 5"), None);
+        assert_eq!(solve("161011: 16 10 13"), None);
+    }
+
+    #[test]
+    fn solvable_sample_lines() {
+        let solved: Vec<u64> = parse(SAMPLE)
+            .unwrap()
+            .iter()
+            .filter(|equation| {
+                equation
+                    .solve(&[Operator::Add, Operator::Multiply])
+                    .is_some()
+            })
+            .map(|equation| equation.test_value)
+            .collect();
+        assert_eq!(solved, vec![190, 3267, 292]);
+    }
+
+    #[test]
+    fn concat() {
+        use Operator::*;
+
+        assert_eq!(Concat.eval(12, 345), Some(12345));
+        assert_eq!(Concat.eval(1, 0), Some(10));
+        assert_eq!(Concat.eval(5, 10), Some(510));
+
+        let equation = &parse("7290: 6 8 6 15").unwrap()[0];
+        assert_eq!(equation.solve(&[Add, Multiply]), None);
+        assert_eq!(
+            equation.solve(&[Add, Multiply, Concat]),
+            Some(vec![Multiply, Concat, Multiply])
+        );
+        // The set of operators is up to the caller, in whatever order.
+        assert_eq!(
+            parse("156: 15 6").unwrap()[0].solve(&[Concat]),
+            Some(vec![Concat])
+        );
+    }
+
+    #[test]
+    fn overflow_is_pruned() {
+        use Operator::*;
+
+        assert_eq!(Multiply.eval(u64::MAX, 2), None);
+        assert_eq!(Concat.eval(u64::MAX, 1), None);
+        assert_eq!(Concat.eval(1, u64::MAX), None);
+
+        // `+` overflows, so the search has to carry on to `*`.
+        let equation = &parse("18446744073709551615: 18446744073709551615 1").unwrap()[0];
+        assert_eq!(equation.solve(&[Add, Multiply]), Some(vec![Multiply]));
+
+        let equation = &parse("18446744073709551615: 18446744073709551615 2").unwrap()[0];
+        assert_eq!(equation.solve(&[Add, Multiply, Concat]), None);
+    }
+
+    #[test]
+    fn undo() {
+        use Operator::*;
+
+        assert_eq!(Add.undo(10, 3), Some(7));
+        assert_eq!(Add.undo(2, 3), None);
+        assert_eq!(Multiply.undo(12, 3), Some(4));
+        assert_eq!(Multiply.undo(13, 3), None);
+        assert_eq!(Concat.undo(12345, 345), Some(12));
+        assert_eq!(Concat.undo(12345, 45), Some(123));
+        assert_eq!(Concat.undo(12345, 44), None);
+        assert_eq!(Concat.undo(345, 345), Some(0));
+    }
+
+    #[test]
+    fn reverse_matches_forward() {
+        use Operator::*;
+
+        for allowed in [&[Add, Multiply][..], &[Add, Multiply, Concat]] {
+            for equation in parse(SAMPLE)
+                .unwrap()
+                .iter()
+                .chain(&parse(Day07::INPUT).unwrap())
+            {
+                let forward = equation.solve(allowed);
+                let reverse = equation.solve_reverse(allowed);
+                assert_eq!(forward.is_some(), reverse.is_some(), "{:?}", equation);
+                if let Some(operators) = reverse {
+                    assert_eq!(equation.evaluate(&operators), Some(equation.test_value));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reverse_through_zero() {
+        use Operator::*;
+
+        // Multiplying by zero gives zero whatever came before, so the reverse search can't undo it
+        // and has to take any operators for the numbers before that don't overflow.
+        for (line, allowed) in [
+            ("0: 5 0", &[Add, Multiply][..]),
+            ("10: 3 0 10", &[Add, Multiply]),
+            ("0: 7 0 0", &[Add, Multiply, Concat]),
+        ] {
+            let equation = &parse(line).unwrap()[0];
+            let forward = equation.solve(allowed);
+            let reverse = equation.solve_reverse(allowed);
+            assert!(forward.is_some(), "{}", line);
+            assert!(reverse.is_some(), "{}", line);
+            assert_eq!(
+                equation.evaluate(&reverse.unwrap()),
+                Some(equation.test_value)
+            );
+        }
+
+        assert_eq!(
+            parse("10: 3 0 10").unwrap()[0].solve_reverse(&[Add, Multiply]),
+            Some(vec![Multiply, Add])
+        );
+        assert_eq!(parse("5: 5 0").unwrap()[0].solve_reverse(&[Multiply]), None);
+        // Zero only helps if the numbers before it can be worked out at all.
+        let equation = &parse("0: 18446744073709551615 2 0").unwrap()[0];
+        assert_eq!(equation.solve(&[Add, Multiply]), None);
+        assert_eq!(equation.solve_reverse(&[Add, Multiply]), None);
+    }
+
+    #[test]
+    fn evaluate_left_to_right() {
+        use Operator::*;
+
+        let equation = &parse("292: 11 6 16 20").unwrap()[0];
+        assert_eq!(equation.evaluate(&[Add, Multiply, Add]), Some(292));
+        assert_eq!(equation.evaluate(&[Add, Multiply]), None);
+    }
+
+    #[test]
+    fn every_solution() {
+        use Operator::*;
+
+        let equation = &parse("3267: 81 40 27").unwrap()[0];
+        let expressions: Vec<String> = equation
+            .solutions(&[Add, Multiply])
+            .iter()
+            .map(|operators| equation.expression(operators))
+            .collect();
+        assert_eq!(expressions, vec!["81 * 40 + 27", "81 + 40 * 27"]);
+
+        let equation = &parse("7290: 6 8 6 15").unwrap()[0];
+        let expressions: Vec<String> = equation
+            .solutions(&[Add, Multiply, Concat])
+            .iter()
+            .map(|operators| equation.expression(operators))
+            .collect();
+        assert_eq!(expressions, vec!["6 * 8 || 6 * 15"]);
+
+        assert!(parse("83: 17 5").unwrap()[0]
+            .solutions(&[Add, Multiply, Concat])
+            .is_empty());
+    }
+
+    #[test]
+    fn every_solution_through_zero() {
+        use Operator::*;
+
+        let expressions = |line: &str| -> Vec<String> {
+            let equation = &parse(line).unwrap()[0];
+            equation
+                .solutions(&[Add, Multiply, Concat])
+                .iter()
+                .map(|operators| equation.expression(operators))
+                .collect()
+        };
+        assert_eq!(expressions("0: 5 0"), vec!["5 * 0"]);
+        assert_eq!(
+            expressions("0: 7 0 0"),
+            vec![
+                "7 * 0 + 0",
+                "7 + 0 * 0",
+                "7 * 0 * 0",
+                "7 || 0 * 0",
+                "7 * 0 || 0"
+            ]
+        );
+    }
+
+    #[test]
+    fn solutions_match_solve() {
+        use Operator::*;
+
+        let with_zeros = parse("0: 5 0\n10: 3 0 10\n0: 7 0 0\n0: 0 0\n3: 0 3\n30: 3 0").unwrap();
+        for equation in parse(SAMPLE).unwrap().into_iter().chain(with_zeros) {
+            let solutions = equation.solutions(&[Add, Multiply, Concat]);
+            assert_eq!(
+                solutions.is_empty(),
+                equation.solve(&[Add, Multiply, Concat]).is_none()
+            );
+            for operators in solutions {
+                assert_eq!(equation.evaluate(&operators), Some(equation.test_value));
+            }
+        }
+    }
+}
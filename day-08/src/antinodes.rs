@@ -0,0 +1,124 @@
+use std::collections::BTreeSet;
+
+use aoc_common::Position;
+
+use crate::AntennaMap;
+
+/// Where a pair of antennas on the same frequency makes antinodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// One antinode on either side of the pair, twice as far from one antenna as the other.
+    Doubled,
+    /// Every grid point in line with the pair, including the antennas themselves.
+    Resonant,
+}
+
+/// Every antinode made by any pair of `antennas`, all of them being on the same frequency.
+pub fn antinodes_for(map: &AntennaMap, antennas: &[Position], rule: Rule) -> BTreeSet<Position> {
+    let mut antinodes = BTreeSet::new();
+    for (i, &a) in antennas.iter().enumerate() {
+        for &b in &antennas[i + 1..] {
+            match rule {
+                Rule::Doubled => {
+                    antinodes.extend(
+                        [a - (b - a), b + (b - a)]
+                            .into_iter()
+                            .filter(|antinode| map.grid.in_bounds(*antinode)),
+                    );
+                }
+                Rule::Resonant => {
+                    // Step by the smallest whole step along the line so no grid point in between is missed.
+                    let delta = b - a;
+                    let divisor = gcd(delta.0.abs(), delta.1.abs());
+                    let step = Position(delta.0 / divisor, delta.1 / divisor);
+
+                    antinodes.extend(walk(map, a, step));
+                    antinodes.extend(walk(map, a, step * -1));
+                }
+            }
+        }
+    }
+
+    antinodes
+}
+
+/// Every antinode on the map, whatever its frequency. Antinodes from different frequencies in the same place count once.
+pub fn antinodes(map: &AntennaMap, rule: Rule) -> BTreeSet<Position> {
+    map.frequencies
+        .values()
+        .flat_map(|antennas| antinodes_for(map, antennas, rule))
+        .collect()
+}
+
+/// Every position from `start` onwards, `step` at a time, until it goes off the map.
+fn walk(map: &AntennaMap, start: Position, step: Position) -> impl Iterator<Item = Position> + '_ {
+    std::iter::successors(Some(start), move |position| Some(*position + step))
+        .take_while(|position| map.grid.in_bounds(*position))
+}
+
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn doubled_pair() {
+        let map = parse("..........\n..........\n..........\n....a.....\n..........\n.....a....\n..........\n..........\n..........\n..........").unwrap();
+        let antinodes = antinodes(&map, Rule::Doubled);
+        assert_eq!(
+            antinodes.into_iter().collect::<Vec<_>>(),
+            vec![Position(3, 1), Position(6, 7)]
+        );
+    }
+
+    #[test]
+    fn doubled_off_the_map() {
+        let map = parse("a.a").unwrap();
+        assert!(antinodes(&map, Rule::Doubled).is_empty());
+    }
+
+    #[test]
+    fn resonant_steps_through_every_grid_point() {
+        // The antennas are two apart, so the point between them is in line too.
+        let map = parse("a.a..").unwrap();
+        assert_eq!(
+            antinodes(&map, Rule::Resonant)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            (0..5).map(|x| Position(x, 0)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn resonant_t_frequency() {
+        let map = parse(
+            "T.........
+...T......
+.T........
+..........
+..........
+..........
+..........
+..........
+..........
+..........",
+        )
+        .unwrap();
+        assert_eq!(antinodes(&map, Rule::Resonant).len(), 9);
+    }
+
+    #[test]
+    fn gcd_of_deltas() {
+        assert_eq!(gcd(4, 6), 2);
+        assert_eq!(gcd(0, 3), 3);
+        assert_eq!(gcd(3, 0), 3);
+    }
+}
@@ -1,20 +1,8 @@
+use aoc_common::input;
+use day_08::{parse, part1::part_1};
+
 fn main() {
-    let input = include_str!("./input.txt");
-    let output = part_1(input);
+    let input = input::from_args(include_str!("./input.txt"), &[], &[]).input;
+    let output = part_1(&input::or_exit(parse(&input)));
     dbg!(output);
 }
-
-fn part_1(input: &str) -> i32 {
-    todo!()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn sample() {
-        let result = part_1("");
-        assert_eq!(result, 0);
-    }
-}
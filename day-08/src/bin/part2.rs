@@ -0,0 +1,8 @@
+use aoc_common::input;
+use day_08::{parse, part2::part_2};
+
+fn main() {
+    let input = input::from_args(include_str!("./input.txt"), &[], &[]).input;
+    let output = part_2(&input::or_exit(parse(&input)));
+    dbg!(output);
+}
@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+
+use aoc_common::{input::normalise, Grid, ParseError, ParseErrorKind, Position, Solution};
+
+pub mod antinodes;
+pub mod part1;
+pub mod part2;
+pub mod render;
+
+/// The antennas on the roof grouped by frequency, along with the map they were read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AntennaMap {
+    pub grid: Grid<char>,
+    pub frequencies: BTreeMap<char, Vec<Position>>,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("./bin/input.txt");
+
+    type Input = AntennaMap;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<AntennaMap, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(map: &AntennaMap) -> i32 {
+        part1::part_1(map)
+    }
+
+    fn part_2(map: &AntennaMap) -> Option<i32> {
+        Some(part2::part_2(map))
+    }
+}
+
+/// Parse the map. Antennas are letters or digits, and `#` is read as an empty space
+/// so the puzzle's examples with their antinodes drawn in can be pasted straight in.
+pub fn parse(input: &str) -> Result<AntennaMap, ParseError> {
+    let input = &*normalise(input);
+    let grid = Grid::try_from(input).map_err(|error| error.with_day(Day08::DAY))?;
+
+    let mut frequencies: BTreeMap<char, Vec<Position>> = BTreeMap::new();
+    for (position, c) in grid.iter() {
+        match c {
+            '.' | '#' => (),
+            c if c.is_ascii_alphanumeric() => frequencies.entry(*c).or_default().push(position),
+            c => {
+                return Err(ParseError::new(
+                    ParseErrorKind::Expected("an antenna or `.`"),
+                    position.1 as usize + 1,
+                    position.0 as usize + 1,
+                    &c.to_string(),
+                )
+                .with_day(Day08::DAY))
+            }
+        }
+    }
+
+    Ok(AntennaMap { grid, frequencies })
+}
+
+/// The sample from the puzzle, shared between both parts' tests.
+#[cfg(test)]
+pub(crate) const SAMPLE: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_frequency_groups() {
+        let map = parse(SAMPLE).unwrap();
+        assert_eq!((map.grid.width(), map.grid.height()), (12, 12));
+        assert_eq!(
+            map.frequencies[&'0'],
+            vec![
+                Position(8, 1),
+                Position(5, 2),
+                Position(7, 3),
+                Position(4, 4)
+            ]
+        );
+        assert_eq!(
+            map.frequencies[&'A'],
+            vec![Position(6, 5), Position(8, 8), Position(9, 9)]
+        );
+    }
+
+    #[test]
+    fn parse_invalid_antenna() {
+        let error = parse("..\n.?").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(ParseErrorKind::Expected("an antenna or `.`"), 2, 2, "?").with_day(8)
+        );
+    }
+
+    #[test]
+    fn parse_trailing_newline_and_crlf() {
+        let sample = "......#....#\n...#....0...\n....#0....#.";
+        let expected = parse(sample).unwrap();
+
+        let with_newline = format!("{}\n", sample);
+        assert_eq!(parse(&with_newline).unwrap(), expected);
+
+        let with_crlf = format!("{}\r\n", sample.replace('\n', "\r\n"));
+        assert_eq!(parse(&with_crlf).unwrap(), expected);
+    }
+}
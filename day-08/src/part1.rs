@@ -0,0 +1,20 @@
+use crate::{
+    antinodes::{antinodes, Rule},
+    AntennaMap,
+};
+
+pub fn part_1(map: &AntennaMap) -> i32 {
+    antinodes(map, Rule::Doubled).len() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    #[test]
+    fn part_1_sample() {
+        let result = part_1(&parse(SAMPLE).unwrap());
+        assert_eq!(result, 14);
+    }
+}
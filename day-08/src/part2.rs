@@ -0,0 +1,20 @@
+use crate::{
+    antinodes::{antinodes, Rule},
+    AntennaMap,
+};
+
+pub fn part_2(map: &AntennaMap) -> i32 {
+    antinodes(map, Rule::Resonant).len() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    #[test]
+    fn part_2_sample() {
+        let result = part_2(&parse(SAMPLE).unwrap());
+        assert_eq!(result, 34);
+    }
+}
@@ -0,0 +1,146 @@
+use aoc_common::{Grid, Position};
+
+use crate::{
+    antinodes::{antinodes_for, Rule},
+    AntennaMap,
+};
+
+/// The frequencies to draw: just the one asked for, or all of them.
+fn selected(
+    map: &AntennaMap,
+    frequency: Option<char>,
+) -> impl Iterator<Item = (&char, &Vec<Position>)> {
+    map.frequencies
+        .iter()
+        .filter(move |(c, _)| frequency.is_none_or(|frequency| **c == frequency))
+}
+
+/// Draw the map with its antinodes as `#`, the same way the puzzle illustrates them.
+/// An antenna is drawn over any antinode in the same place.
+/// With a `frequency`, only that frequency's antennas and antinodes are drawn.
+pub fn render(map: &AntennaMap, rule: Rule, frequency: Option<char>) -> String {
+    let mut grid = Grid::new(map.grid.width(), map.grid.height(), '.');
+    for (_, antennas) in selected(map, frequency) {
+        for antinode in antinodes_for(map, antennas, rule) {
+            grid.set(antinode, '#');
+        }
+    }
+    for (c, antennas) in selected(map, frequency) {
+        for antenna in antennas {
+            grid.set(*antenna, *c);
+        }
+    }
+
+    grid.to_string()
+}
+
+/// One line per frequency with how many antennas and antinodes it has.
+pub fn legend(map: &AntennaMap, rule: Rule, frequency: Option<char>) -> String {
+    selected(map, frequency)
+        .map(|(c, antennas)| {
+            format!(
+                "{}: {} antennas, {} antinodes",
+                c,
+                antennas.len(),
+                antinodes_for(map, antennas, rule).len()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, SAMPLE};
+
+    #[test]
+    fn render_doubled() {
+        let expected = "......#....#
+...#....0...
+....#0....#.
+..#....0....
+....0....#..
+.#....A.....
+...#........
+#......#....
+........A...
+.........A..
+..........#.
+..........#.";
+        assert_eq!(
+            render(&parse(SAMPLE).unwrap(), Rule::Doubled, None),
+            expected
+        );
+    }
+
+    #[test]
+    fn render_resonant() {
+        let expected = "##....#....#
+.#.#....0...
+..#.#0....#.
+..##...0....
+....0....#..
+.#...#A....#
+...#..#.....
+#....#.#....
+..#.....A...
+....#....A..
+.#........#.
+...#......##";
+        assert_eq!(
+            render(&parse(SAMPLE).unwrap(), Rule::Resonant, None),
+            expected
+        );
+    }
+
+    #[test]
+    fn render_illustrated_example() {
+        // The antinodes in an illustration are parsed as empty space, so it should render back the same.
+        let illustrated = "T....#....
+...T......
+.T....#...
+.........#
+..#.......
+..........
+...#......
+..........
+....#.....
+..........";
+        let map = parse(illustrated).unwrap();
+        assert_eq!(render(&map, Rule::Resonant, None), illustrated);
+    }
+
+    #[test]
+    fn render_one_frequency() {
+        let expected = "............
+...#........
+....#.......
+............
+............
+......A.....
+............
+.......#....
+........A...
+.........A..
+..........#.
+..........#.";
+        assert_eq!(
+            render(&parse(SAMPLE).unwrap(), Rule::Doubled, Some('A')),
+            expected
+        );
+    }
+
+    #[test]
+    fn legend_per_frequency() {
+        let map = parse(SAMPLE).unwrap();
+        assert_eq!(
+            legend(&map, Rule::Doubled, None),
+            "0: 4 antennas, 10 antinodes\nA: 3 antennas, 5 antinodes"
+        );
+        assert_eq!(
+            legend(&map, Rule::Doubled, Some('A')),
+            "A: 3 antennas, 5 antinodes"
+        );
+    }
+}
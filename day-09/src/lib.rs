@@ -0,0 +1,99 @@
+use aoc_common::{input::normalise, ParseError, ParseErrorKind, Solution};
+
+pub mod part1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Block {
+    /// File with an id and length.  
+    /// ### id
+    /// Refers to the position in the list it was before it was rearranged.  
+    /// ### length
+    /// Refers to the length of the file.  
+    File { id: u64, length: u64 },
+    /// A free space block with the length of space
+    FreeSpace { length: u64 },
+}
+
+impl Block {
+    pub fn get_length(self) -> u64 {
+        match self {
+            Block::File { id: _, length } => length,
+            Block::FreeSpace { length } => length,
+        }
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = include_str!("./bin/input.txt");
+
+    type Input = Vec<Block>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Vec<Block>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(disk_map: &Vec<Block>) -> i64 {
+        part1::part_1(disk_map)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Block>, ParseError> {
+    let input = &*normalise(input);
+    // Keep an incrementing id.
+    // Parse the input and generate a list of blocks.
+    let block_numbers = input
+        .char_indices()
+        .map(|(index, c)| {
+            c.to_digit(10).map(|digit| digit as u64).ok_or_else(|| {
+                ParseError::at(input, &input[index..], ParseErrorKind::Expected("a digit"))
+                    .with_day(Day09::DAY)
+            })
+        })
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+
+    // Keep state of the file ids. As we find more files, increment by 1;
+    let mut file_id: u64 = 0;
+    let mut disk_map: Vec<Block> = vec![];
+
+    for (i, num) in block_numbers.into_iter().enumerate() {
+        if i % 2 == 0 {
+            disk_map.push(Block::File {
+                id: file_id,
+                length: num,
+            });
+            file_id += 1;
+        } else {
+            disk_map.push(Block::FreeSpace { length: num });
+        }
+    }
+
+    Ok(disk_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bad_digit() {
+        let error = parse("23331x3").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (Some(9), 1, 6));
+        assert_eq!(error.kind, ParseErrorKind::Expected("a digit"));
+    }
+
+    #[test]
+    fn parse_trailing_newline_and_crlf() {
+        let sample = "2333133121414131402";
+        let expected = parse(sample).unwrap();
+
+        let with_newline = format!("{}\n", sample);
+        assert_eq!(parse(&with_newline).unwrap(), expected);
+
+        let with_crlf = format!("{}\r\n", sample.replace('\n', "\r\n"));
+        assert_eq!(parse(&with_crlf).unwrap(), expected);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::Block;
+
+pub fn part_1(disk_map: &[Block]) -> i64 {
+    // Expand the free space blocks
+    // Keep a mut vec that allows us to hold different kinds of blocks.
+    let mut file_system: Vec<Block> = vec![];
+    for block in disk_map {
+        for _ in 0..block.get_length() {
+            // Copying the blocks into the filesystem.
+            file_system.push(*block);
+        }
+    }
+
+    // When moving, swap the file blocks into the earliest free space block.
+    // We are done when the index of the next free space block is greater than the block we are moving.
+    'forward_loop: for forward_index in 0..file_system.len() {
+        if let Block::FreeSpace { .. } = &file_system[forward_index] {
+            'reverse_loop: for reverse_index in (0..file_system.len()).rev() {
+                if let Block::File { .. } = &file_system[reverse_index] {
+                    // println!(
+                    //     "Swapping file {} ({:?}) with freespace {} ({:?})",
+                    //     reverse_index,
+                    //     &file_system[reverse_index],
+                    //     forward_index,
+                    //     &file_system[forward_index]
+                    // );
+                    file_system.swap(reverse_index, forward_index);
+                    break 'reverse_loop;
+                }
+
+                if reverse_index <= forward_index {
+                    break 'forward_loop;
+                }
+            }
+        }
+    }
+
+    // dbg!(&file_system);
+
+    // Run the calculation after the blocks are in the correct order.
+    file_system
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (index, block)| {
+            if let Block::File { id, length: _ } = block {
+                return acc + (index as u64 * id) as i64;
+            }
+
+            acc
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn sample() {
+        let result = part_1(&parse("2333133121414131402").unwrap());
+        assert_eq!(result, 1928);
+    }
+}